@@ -0,0 +1,205 @@
+use std::fmt;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+
+use crate::input::{Input, InputSource};
+use crate::parse::{self, ParseError};
+use crate::solution::Day;
+
+/// Default name of the answers registry
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// The expected answer to one part of a day for a given input file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expected {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String
+}
+
+/// Loads an answers registry. Each line is "<day> <part> <input file> <answer>",
+/// blank lines and lines starting with '#' are ignored
+pub fn load(input: &mut dyn BufRead) -> Result<Vec<Expected>, ParseError> {
+    let mut expected = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let text = line.text.trim();
+
+        if text.is_empty() || text.starts_with('#') {
+            continue
+        }
+
+        let mut split = text.split_whitespace();
+
+        let day_str = line.next(&mut split, "day")?;
+        let day = line.parse::<u8>(day_str)?;
+
+        if !(1..=25).contains(&day) {
+            Err(line.invalid(day_str, "day must be 1 to 25"))?
+        }
+
+        let part_str = line.next(&mut split, "part")?;
+        let part = line.parse::<u8>(part_str)?;
+
+        if !(1..=2).contains(&part) {
+            Err(line.invalid(part_str, "part must be 1 or 2"))?
+        }
+
+        let input = line.next(&mut split, "input file")?;
+
+        // The answer is the rest of the line
+        let answer_start = input.as_ptr() as usize - text.as_ptr() as usize + input.len();
+        let answer = text[answer_start..].trim();
+
+        if answer.is_empty() {
+            Err(line.missing("answer"))?
+        }
+
+        expected.push(Expected {
+            day,
+            part,
+            input: input.to_string(),
+            answer: answer.to_string()
+        });
+    }
+
+    Ok(expected)
+}
+
+/// Paths searched for the answers registry when none is given
+pub fn search_paths() -> Vec<PathBuf> {
+    vec![
+        PathBuf::from(ANSWERS_FILE),
+        Path::new("..").join(ANSWERS_FILE)
+    ]
+}
+
+/// Outcome of checking one part of a day
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { got: String },
+    Error(String),
+    Missing
+}
+
+/// The verdict for one part of a day. Missing parts have no input or expected answer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub day: u8,
+    pub part: u8,
+    pub input: Option<String>,
+    pub expected: Option<String>,
+    pub verdict: Verdict
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Day {} part {}", self.day, self.part)?;
+
+        if let Some(input) = &self.input {
+            write!(f, " ({})", input)?;
+        }
+
+        match &self.verdict {
+            Verdict::Pass => write!(f, ": pass"),
+            Verdict::Fail { got } => write!(f, ": FAIL, expected {} got {}", self.expected.as_deref().unwrap_or(""), got),
+            Verdict::Error(e) => write!(f, ": ERROR, {}", e),
+            Verdict::Missing => write!(f, ": missing")
+        }
+    }
+}
+
+/// Runs each day against the expected answers, with input files relative to base.
+/// Each input is parsed once for all of its expected parts. Parts of the days with
+/// no expected answer are reported as missing
+pub fn verify(days: &[&Day], expected: &[Expected], base: &Path) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for day in days {
+        let day_expected: Vec<&Expected> = expected.iter().filter(|e| e.day == day.day).collect();
+
+        // Group the expected answers by input file, keeping registry order
+        let mut inputs: Vec<&str> = Vec::new();
+
+        for e in &day_expected {
+            if !inputs.contains(&&e.input[..]) {
+                inputs.push(&e.input);
+            }
+        }
+
+        let check = |e: &Expected, verdict| Check {
+            day: e.day,
+            part: e.part,
+            input: Some(e.input.clone()),
+            expected: Some(e.answer.clone()),
+            verdict
+        };
+
+        for input_name in inputs {
+            let (input_expected, no_part): (Vec<&Expected>, Vec<&Expected>) = day_expected.iter()
+                .filter(|e| e.input == input_name)
+                .partition(|e| e.part <= day.parts);
+
+            for e in no_part {
+                checks.push(check(e, Verdict::Error(format!("Day {} has no part {}", day.day, e.part))));
+            }
+
+            let parts: Vec<u8> = input_expected.iter().map(|e| e.part).collect();
+
+            let input = Input {
+                day: day.day,
+                source: InputSource::File(base.join(input_name))
+            };
+
+            match day.run_input(&input, &parts) {
+                Ok(answers) => {
+                    for (e, answer) in input_expected.into_iter().zip(answers) {
+                        let verdict = if answer.value == e.answer {
+                            Verdict::Pass
+                        } else {
+                            Verdict::Fail { got: answer.value }
+                        };
+
+                        checks.push(check(e, verdict));
+                    }
+                }
+                Err(err) => {
+                    for e in input_expected {
+                        checks.push(check(e, Verdict::Error(err.to_string())));
+                    }
+                }
+            }
+        }
+
+        for part in 1..=day.parts {
+            if !day_expected.iter().any(|e| e.part == part) {
+                checks.push(Check {
+                    day: day.day,
+                    part,
+                    input: None,
+                    expected: None,
+                    verdict: Verdict::Missing
+                });
+            }
+        }
+    }
+
+    checks
+}
+
+#[test]
+fn load_test() {
+    let registry = "# Comment\n\n7 1 input07.txt 139\n21 2 input21.txt prxmdlz,ncjv\n";
+
+    assert_eq!(vec![
+        Expected { day: 7, part: 1, input: "input07.txt".to_string(), answer: "139".to_string() },
+        Expected { day: 21, part: 2, input: "input21.txt".to_string(), answer: "prxmdlz,ncjv".to_string() }
+    ], load(&mut registry.as_bytes()).unwrap());
+
+    assert_eq!("line 1 column 1: invalid '26': day must be 1 to 25", load(&mut "26 1 x 1".as_bytes()).unwrap_err().to_string());
+    assert_eq!("line 1 column 14: missing answer", load(&mut "7 1 input.txt".as_bytes()).unwrap_err().to_string());
+}
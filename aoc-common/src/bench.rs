@@ -0,0 +1,240 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Read, Write};
+use std::time::{Duration, Instant};
+
+use crate::input::Input;
+use crate::parse::{self, ParseError};
+use crate::solution::Day;
+
+/// Header line of the benchmark results file
+pub const CSV_HEADER: &str = "day,phase,runs,min_ns,median_ns,mean_ns,max_ns";
+
+/// Slowdowns smaller than this are treated as noise whatever the percentage
+pub const MIN_SLOWDOWN: Duration = Duration::from_micros(100);
+
+/// Run time statistics for one phase (parse, part1 or part2) of a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timing {
+    pub day: u8,
+    pub phase: String,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration
+}
+
+impl Timing {
+    fn from_runs(day: u8, phase: &str, mut runs: Vec<Duration>) -> Timing {
+        runs.sort();
+
+        let total: Duration = runs.iter().sum();
+
+        Timing {
+            day,
+            phase: phase.to_string(),
+            runs: runs.len(),
+            min: runs[0],
+            median: runs[runs.len() / 2],
+            mean: total / runs.len() as u32,
+            max: runs[runs.len() - 1]
+        }
+    }
+}
+
+/// Times parsing the day's input and solving each part, repeat times each.
+/// The input is read in to memory first so file access isn't timed
+pub fn bench(day: &Day, input: &Input, repeat: usize) -> Result<Vec<Timing>, Box<dyn Error>> {
+    let (name, mut reader) = input.open()?;
+
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+
+    let repeat = repeat.max(1);
+
+    let mut parse_runs = Vec::new();
+    let mut model = None;
+
+    for _ in 0..repeat {
+        let start = Instant::now();
+        let parsed = (day.parse)(&mut &data[..]).map_err(|e| e.in_file(&name))?;
+        parse_runs.push(start.elapsed());
+
+        model = Some(parsed);
+    }
+
+    let model = model.ok_or("No parse runs")?;
+
+    let mut timings = vec![Timing::from_runs(day.day, "parse", parse_runs)];
+
+    for part in 1..=day.parts {
+        let mut solve_runs = Vec::new();
+
+        for _ in 0..repeat {
+            let start = Instant::now();
+            (day.solve)(model.as_ref(), part)?;
+            solve_runs.push(start.elapsed());
+        }
+
+        timings.push(Timing::from_runs(day.day, &format!("part{}", part), solve_runs));
+    }
+
+    Ok(timings)
+}
+
+/// Writes timings in CSV format, durations in nanoseconds
+pub fn write_csv(timings: &[Timing], out: &mut dyn Write) -> io::Result<()> {
+    writeln!(out, "{}", CSV_HEADER)?;
+
+    for t in timings {
+        writeln!(out, "{},{},{},{},{},{},{}", t.day, t.phase, t.runs,
+            t.min.as_nanos(), t.median.as_nanos(), t.mean.as_nanos(), t.max.as_nanos())?;
+    }
+
+    Ok(())
+}
+
+/// Loads timings written by write_csv
+pub fn load_csv(input: &mut dyn BufRead) -> Result<Vec<Timing>, ParseError> {
+    let mut timings = Vec::new();
+
+    let mut lines = parse::lines(input);
+
+    let header = lines.expect("CSV header")?;
+
+    if header.text != CSV_HEADER {
+        Err(header.unexpected(&header.text, CSV_HEADER))?
+    }
+
+    for line_result in lines {
+        let line = line_result?;
+
+        if line.text.is_empty() {
+            continue
+        }
+
+        let mut fields = line.text.split(',');
+
+        let day = line.parse(line.next(&mut fields, "day")?)?;
+        let phase = line.next(&mut fields, "phase")?.to_string();
+        let runs = line.parse(line.next(&mut fields, "runs")?)?;
+
+        let mut durations = [Duration::default(); 4];
+
+        for (duration, what) in durations.iter_mut().zip(&["min_ns", "median_ns", "mean_ns", "max_ns"]) {
+            *duration = Duration::from_nanos(line.parse(line.next(&mut fields, what)?)?);
+        }
+
+        if let Some(extra) = fields.next() {
+            Err(line.unexpected(extra, "end of line"))?
+        }
+
+        timings.push(Timing {
+            day,
+            phase,
+            runs,
+            min: durations[0],
+            median: durations[1],
+            mean: durations[2],
+            max: durations[3]
+        });
+    }
+
+    Ok(timings)
+}
+
+/// A phase's median time compared with a baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub phase: String,
+    pub baseline: Duration,
+    pub current: Duration,
+    pub slower: bool
+}
+
+impl Comparison {
+    /// Percentage change from the baseline
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_secs_f64();
+
+        if baseline == 0.0 {
+            0.0
+        } else {
+            (self.current.as_secs_f64() - baseline) * 100.0 / baseline
+        }
+    }
+}
+
+/// Compares median times with the baseline, flagging phases more than
+/// threshold percent slower (ignoring slowdowns below MIN_SLOWDOWN).
+/// Phases missing from the baseline are skipped
+pub fn compare(current: &[Timing], baseline: &[Timing], threshold: f64) -> Vec<Comparison> {
+    current.iter().filter_map(|t| {
+        let b = baseline.iter().find(|b| b.day == t.day && b.phase == t.phase)?;
+
+        let mut comparison = Comparison {
+            day: t.day,
+            phase: t.phase.clone(),
+            baseline: b.median,
+            current: t.median,
+            slower: false
+        };
+
+        comparison.slower = t.median > b.median + MIN_SLOWDOWN && comparison.change() > threshold;
+
+        Some(comparison)
+    }).collect()
+}
+
+/// Formats a duration in the most readable unit
+pub struct Readable(pub Duration);
+
+impl fmt::Display for Readable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let secs = self.0.as_secs_f64();
+
+        if secs >= 1.0 {
+            write!(f, "{:.3}s", secs)
+        } else if secs >= 0.001 {
+            write!(f, "{:.3}ms", secs * 1_000.0)
+        } else {
+            write!(f, "{:.3}us", secs * 1_000_000.0)
+        }
+    }
+}
+
+#[test]
+fn csv_test() {
+    let timings = vec![
+        Timing::from_runs(7, "parse", vec![Duration::from_nanos(30), Duration::from_nanos(10), Duration::from_nanos(20)]),
+        Timing::from_runs(7, "part1", vec![Duration::from_micros(5)])
+    ];
+
+    assert_eq!((Duration::from_nanos(10), Duration::from_nanos(20), Duration::from_nanos(20), Duration::from_nanos(30)),
+        (timings[0].min, timings[0].median, timings[0].mean, timings[0].max));
+
+    let mut csv = Vec::new();
+    write_csv(&timings, &mut csv).unwrap();
+
+    assert_eq!(format!("{}\n7,parse,3,10,20,20,30\n7,part1,1,5000,5000,5000,5000\n", CSV_HEADER), String::from_utf8(csv.clone()).unwrap());
+    assert_eq!(timings, load_csv(&mut &csv[..]).unwrap());
+
+    assert!(load_csv(&mut "day,phase\n".as_bytes()).is_err(), "Wrong header should fail");
+}
+
+#[test]
+fn compare_test() {
+    let timing = |phase: &str, ms| Timing::from_runs(7, phase, vec![Duration::from_millis(ms)]);
+
+    let baseline = vec![timing("parse", 10), timing("part1", 10)];
+    let current = vec![timing("parse", 11), timing("part1", 20), timing("part2", 5)];
+
+    let comparisons = compare(&current, &baseline, 20.0);
+
+    assert_eq!(2, comparisons.len());
+    assert!(!comparisons[0].slower, "10% slower is within the threshold");
+    assert!(comparisons[1].slower, "100% slower is over the threshold");
+    assert_eq!(100.0, comparisons[1].change());
+}
@@ -0,0 +1,413 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+/// How the cells of an automaton are connected
+pub trait Topology {
+    type Cell: Clone + Eq + Hash;
+
+    /// The cells which affect a cell. Must be symmetric: if b is a neighbour
+    /// of a then a is a neighbour of b
+    fn neighbours(&self, cell: &Self::Cell) -> Vec<Self::Cell>;
+
+    /// Every cell for bounded topologies, None for unbounded ones
+    fn cells(&self) -> Option<Vec<Self::Cell>> {
+        None
+    }
+}
+
+/// Neighbour lists for the chosen cells of a grid
+type CellMap = HashMap<(usize, usize), Vec<(usize, usize)>>;
+
+fn cell_map<T, F, N>(grid: &Grid<T>, is_cell: F, neighbours: N) -> CellMap
+where F: Fn(&T) -> bool, N: Fn(usize, usize) -> Vec<(usize, usize)> {
+    grid.iter()
+        .filter(|(_, c)| is_cell(c))
+        .map(|((x, y), _)| {
+            let cells = neighbours(x, y).into_iter().filter(|&pos| is_cell(&grid[pos])).collect();
+            ((x, y), cells)
+        })
+        .collect()
+}
+
+/// The chosen cells of a grid, connected to the cells adjacent in all 8 directions
+pub struct Rect {
+    cells: CellMap
+}
+
+impl Rect {
+    pub fn new<T, F>(grid: &Grid<T>, is_cell: F) -> Rect
+    where F: Fn(&T) -> bool {
+        Rect {
+            cells: cell_map(grid, &is_cell, |x, y| grid.neighbours8(x, y).collect())
+        }
+    }
+}
+
+impl Topology for Rect {
+    type Cell = (usize, usize);
+
+    fn neighbours(&self, cell: &Self::Cell) -> Vec<Self::Cell> {
+        self.cells.get(cell).cloned().unwrap_or_default()
+    }
+
+    fn cells(&self) -> Option<Vec<Self::Cell>> {
+        Some(self.cells.keys().cloned().collect())
+    }
+}
+
+/// The chosen cells of a grid, connected to the first cell seen in each of
+/// the 8 directions
+pub struct LineOfSight {
+    cells: CellMap
+}
+
+impl LineOfSight {
+    pub fn new<T, F>(grid: &Grid<T>, is_cell: F) -> LineOfSight
+    where F: Fn(&T) -> bool {
+        LineOfSight {
+            cells: cell_map(grid, &is_cell, |x, y| grid.line_of_sight(x, y, &is_cell).collect())
+        }
+    }
+}
+
+impl Topology for LineOfSight {
+    type Cell = (usize, usize);
+
+    fn neighbours(&self, cell: &Self::Cell) -> Vec<Self::Cell> {
+        self.cells.get(cell).cloned().unwrap_or_default()
+    }
+
+    fn cells(&self) -> Option<Vec<Self::Cell>> {
+        Some(self.cells.keys().cloned().collect())
+    }
+}
+
+/// Unbounded N dimensional space, each cell connected to the 3^N - 1 cells around it
+pub struct NDim<const N: usize> {
+    offsets: Vec<[i64; N]>
+}
+
+impl<const N: usize> NDim<N> {
+    pub fn new() -> NDim<N> {
+        let mut offsets = vec![[0; N]];
+
+        for dim in 0..N {
+            offsets = offsets.into_iter().flat_map(|o| {
+                (-1..=1).map(move |d| {
+                    let mut o = o;
+                    o[dim] = d;
+                    o
+                })
+            }).collect();
+        }
+
+        offsets.retain(|o| o.iter().any(|&d| d != 0));
+
+        NDim { offsets }
+    }
+}
+
+impl<const N: usize> Default for NDim<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Topology for NDim<N> {
+    type Cell = [i64; N];
+
+    fn neighbours(&self, cell: &Self::Cell) -> Vec<Self::Cell> {
+        self.offsets.iter().map(|o| {
+            let mut n = *cell;
+
+            for (c, d) in n.iter_mut().zip(o) {
+                *c += d;
+            }
+
+            n
+        }).collect()
+    }
+}
+
+// Hexagon coordinate systems at https://www.redblobgames.com/grids/hexagons/
+
+/// A hexagon in the odd-r offset layout, where odd rows are shifted right
+#[derive(Debug, Default, Hash, Eq, PartialEq, Clone)]
+pub struct HexCoord {
+    pub q: isize, // Column
+    pub r: isize  // Row
+}
+
+/// Unbounded plane of hexagons with pointy tops, each connected to its 6 sides
+pub struct Hex;
+
+impl Topology for Hex {
+    type Cell = HexCoord;
+
+    fn neighbours(&self, c: &HexCoord) -> Vec<HexCoord> {
+        // Diagonal neighbours shift column depending on the row
+        let diag = if c.r & 1 == 1 { 1 } else { -1 };
+
+        [(0, -1), (0, 1), (-1, 0), (1, 0), (-1, diag), (1, diag)].iter()
+            .map(|&(dr, dq)| HexCoord { q: c.q + dq, r: c.r + dr })
+            .collect()
+    }
+}
+
+/// Birth/survival rule: a dead cell comes alive with a birth count of live
+/// neighbours, a live cell stays alive with a survive count
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survive: Vec<usize>
+}
+
+impl Rule {
+    pub fn new(birth: &[usize], survive: &[usize]) -> Rule {
+        Rule {
+            birth: birth.to_vec(),
+            survive: survive.to_vec()
+        }
+    }
+
+    /// Whether a cell is alive in the next generation
+    pub fn next(&self, alive: bool, neighbours: usize) -> bool {
+        if alive {
+            self.survive.contains(&neighbours)
+        } else {
+            self.birth.contains(&neighbours)
+        }
+    }
+}
+
+/// Parses rules in B/S notation, e.g. "B3/S23"
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Rule, String> {
+        let counts = |part: &str, prefix| -> Result<Vec<usize>, String> {
+            let digits = part.strip_prefix(prefix)
+                .ok_or_else(|| format!("'{}' does not start with {}", part, prefix))?;
+
+            digits.chars().map(|c| {
+                c.to_digit(10).map(|d| d as usize).ok_or_else(|| format!("'{}' is not a count", c))
+            }).collect()
+        };
+
+        let mut split = s.split('/');
+
+        let birth = counts(split.next().unwrap_or(""), 'B')?;
+        let survive = counts(split.next().ok_or_else(|| format!("'{}' has no /S part", s))?, 'S')?;
+
+        if split.next().is_some() {
+            Err(format!("'{}' has too many parts", s))?
+        }
+
+        Ok(Rule { birth, survive })
+    }
+}
+
+/// How a run of the automaton ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// The state at this generation never changes
+    Fixed(usize),
+    /// The state at start repeats every period generations
+    Cycle { start: usize, period: usize },
+    /// The step limit was reached
+    Limit
+}
+
+/// A cellular automaton holding the set of live cells
+pub struct Automaton<T: Topology> {
+    topology: T,
+    rule: Rule,
+    universe: Option<Vec<T::Cell>>,
+    live: HashSet<T::Cell>,
+    generation: usize
+}
+
+impl<T: Topology> Automaton<T> {
+    /// Note that a rule with birth on 0 neighbours needs a bounded topology
+    pub fn new<I>(topology: T, rule: Rule, live: I) -> Automaton<T>
+    where I: IntoIterator<Item = T::Cell> {
+        Automaton {
+            universe: topology.cells(),
+            topology,
+            rule,
+            live: live.into_iter().collect(),
+            generation: 0
+        }
+    }
+
+    pub fn live(&self) -> &HashSet<T::Cell> {
+        &self.live
+    }
+
+    /// Number of steps taken
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    fn next_state(&self) -> HashSet<T::Cell> {
+        let mut counts: HashMap<T::Cell, usize> = HashMap::new();
+
+        for cell in &self.live {
+            for n in self.topology.neighbours(cell) {
+                *counts.entry(n).or_insert(0) += 1;
+            }
+        }
+
+        let alive = |cell: &T::Cell| {
+            self.rule.next(self.live.contains(cell), counts.get(cell).copied().unwrap_or(0))
+        };
+
+        match &self.universe {
+            Some(cells) => cells.iter().filter(|c| alive(c)).cloned().collect(),
+            None => counts.keys().chain(self.live.iter()).filter(|c| alive(c)).cloned().collect()
+        }
+    }
+
+    /// Advances one generation
+    pub fn step(&mut self) {
+        self.live = self.next_state();
+        self.generation += 1;
+    }
+
+    /// Advances steps generations
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    /// Advances until the state stops changing or repeats, or until the
+    /// generation reaches limit. States are looked up by hash and compared
+    /// in full, so hash collisions can't report a false cycle
+    pub fn settle(&mut self, limit: usize) -> Outcome {
+        let mut seen: HashMap<u64, Vec<_>> = HashMap::new();
+
+        seen.entry(self.state_hash()).or_default().push((self.live.clone(), self.generation));
+
+        while self.generation < limit {
+            let next = self.next_state();
+
+            if next == self.live {
+                let fixed = self.generation;
+                self.generation += 1;
+
+                return Outcome::Fixed(fixed)
+            }
+
+            self.live = next;
+            self.generation += 1;
+
+            let states = seen.entry(self.state_hash()).or_default();
+
+            if let Some((_, start)) = states.iter().find(|(state, _)| *state == self.live) {
+                return Outcome::Cycle { start: *start, period: self.generation - start }
+            }
+
+            states.push((self.live.clone(), self.generation));
+        }
+
+        Outcome::Limit
+    }
+
+    /// Hash of the live cells, independent of set order
+    fn state_hash(&self) -> u64 {
+        self.live.iter().fold(0, |acc: u64, cell| {
+            let mut hash = DefaultHasher::new();
+            cell.hash(&mut hash);
+            acc.wrapping_add(hash.finish())
+        })
+    }
+}
+
+#[test]
+fn rule_test() {
+    let life: Rule = "B3/S23".parse().unwrap();
+
+    assert_eq!(Rule::new(&[3], &[2, 3]), life);
+    assert!(life.next(false, 3));
+    assert!(!life.next(true, 4));
+    assert_eq!(Rule::new(&[], &[0]), "B/S0".parse().unwrap());
+
+    assert!("B3".parse::<Rule>().is_err());
+    assert!("S23/B3".parse::<Rule>().is_err());
+    assert!("B3/S2x".parse::<Rule>().is_err());
+}
+
+#[test]
+fn topology_test() {
+    assert_eq!(8, NDim::<2>::new().neighbours(&[0, 0]).len());
+    assert_eq!(80, NDim::<4>::new().neighbours(&[0, 0, 0, 0]).len());
+
+    // Each hex neighbour should lead back
+    for cell in [HexCoord { q: 0, r: 0 }, HexCoord { q: 3, r: 1 }].iter() {
+        let neighbours = Hex.neighbours(cell);
+
+        assert_eq!(6, neighbours.iter().collect::<HashSet<_>>().len());
+        assert!(neighbours.iter().all(|n| Hex.neighbours(n).contains(cell)));
+    }
+
+    let grid = Grid::from_rows(vec![
+        vec![true, false, true],
+        vec![false, false, false],
+        vec![true, false, true]
+    ]).unwrap();
+
+    assert_eq!(0, Rect::new(&grid, |&c| c).neighbours(&(0, 0)).len());
+    assert_eq!(3, LineOfSight::new(&grid, |&c| c).neighbours(&(0, 0)).len());
+    assert_eq!(4, LineOfSight::new(&grid, |&c| c).cells().unwrap().len());
+}
+
+#[test]
+fn settle_test() {
+    let life: Rule = "B3/S23".parse().unwrap();
+
+    // Block is still life
+    let mut block = Automaton::new(NDim::<2>::new(), life.clone(), vec![[0, 0], [0, 1], [1, 0], [1, 1]]);
+    assert_eq!(Outcome::Fixed(0), block.settle(10));
+
+    // Blinker oscillates with period 2
+    let mut blinker = Automaton::new(NDim::<2>::new(), life.clone(), vec![[0, 0], [1, 0], [2, 0]]);
+    assert_eq!(Outcome::Cycle { start: 0, period: 2 }, blinker.settle(10));
+
+    // Glider moves away forever
+    let mut glider = Automaton::new(NDim::<2>::new(), life, vec![[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]]);
+    assert_eq!(Outcome::Limit, glider.settle(20));
+    assert_eq!(20, glider.generation());
+    assert_eq!(5, glider.live().len());
+
+    // Every state the same size collides, the pattern spreads forever
+    let mut spread = Automaton::new(Line, "B1/S".parse().unwrap(), vec![Collide(0)]);
+    assert_eq!(Outcome::Limit, spread.settle(10));
+}
+
+/// Cell whose hashes always collide
+#[cfg(test)]
+#[derive(Clone, PartialEq, Eq)]
+struct Collide(i64);
+
+#[cfg(test)]
+impl Hash for Collide {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// Unbounded line of colliding cells
+#[cfg(test)]
+struct Line;
+
+#[cfg(test)]
+impl Topology for Line {
+    type Cell = Collide;
+
+    fn neighbours(&self, cell: &Collide) -> Vec<Collide> {
+        vec![Collide(cell.0 - 1), Collide(cell.0 + 1)]
+    }
+}
@@ -0,0 +1,174 @@
+use std::cell::RefCell;
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set when debug output should be suppressed, e.g. for JSON output
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static REPORT: RefCell<Report> = RefCell::new(Report::default());
+
+    /// Output collected from this thread when capturing
+    static CAPTURE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// A statistic recorded while solving
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stat {
+    Int(i128),
+    Text(String)
+}
+
+macro_rules! stat_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Stat {
+            fn from(n: $t) -> Stat {
+                Stat::Int(n as i128)
+            }
+        })*
+    }
+}
+
+stat_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<&str> for Stat {
+    fn from(s: &str) -> Stat {
+        Stat::Text(s.to_string())
+    }
+}
+
+impl From<String> for Stat {
+    fn from(s: String) -> Stat {
+        Stat::Text(s)
+    }
+}
+
+impl fmt::Display for Stat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stat::Int(n) => write!(f, "{}", n),
+            Stat::Text(s) => write!(f, "{}", s)
+        }
+    }
+}
+
+/// Statistics and warnings recorded on this thread since the last take
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    pub stats: Vec<(String, Stat)>,
+    pub warnings: Vec<String>
+}
+
+/// Suppresses (or re-enables) debug output from the solutions
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+pub fn quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Records a named statistic
+pub fn stat<V: Into<Stat>>(name: &str, value: V) {
+    REPORT.with(|r| r.borrow_mut().stats.push((name.to_string(), value.into())));
+}
+
+/// Records a warning, also printing it unless quiet
+pub fn warn<S: Into<String>>(message: S) {
+    let message = message.into();
+
+    if !quiet() && !captured(format_args!("Warning: {}\n", message)) {
+        eprintln!("Warning: {}", message);
+    }
+
+    REPORT.with(|r| r.borrow_mut().warnings.push(message));
+}
+
+/// Returns everything recorded on this thread, clearing it
+pub fn take() -> Report {
+    REPORT.with(|r| r.replace(Report::default()))
+}
+
+/// Starts collecting debug output and warnings from this thread instead of
+/// printing them
+pub fn capture() {
+    CAPTURE.with(|c| *c.borrow_mut() = Some(String::new()));
+}
+
+/// Stops collecting output from this thread, returning what was collected
+pub fn take_output() -> String {
+    CAPTURE.with(|c| c.borrow_mut().take().unwrap_or_default())
+}
+
+/// Whether this thread's output is being collected
+pub fn capturing() -> bool {
+    CAPTURE.with(|c| c.borrow().is_some())
+}
+
+/// Adds to the collected output, returning false if this thread isn't capturing
+fn captured(args: fmt::Arguments) -> bool {
+    CAPTURE.with(|c| match c.borrow_mut().as_mut() {
+        Some(output) => output.write_fmt(args).is_ok(),
+        None => false
+    })
+}
+
+/// Prints debug output, or collects it if this thread is capturing
+pub fn print(args: fmt::Arguments) {
+    if !captured(args) {
+        print!("{}", args);
+    }
+}
+
+/// Prints debug output with a newline unless quiet
+#[macro_export]
+macro_rules! debug {
+    () => {
+        if !$crate::diag::quiet() {
+            $crate::diag::print(format_args!("\n"));
+        }
+    };
+    ($($arg:tt)*) => {
+        if !$crate::diag::quiet() {
+            $crate::diag::print(format_args!("{}\n", format_args!($($arg)*)));
+        }
+    }
+}
+
+/// Prints debug output unless quiet
+#[macro_export]
+macro_rules! debug_print {
+    ($($arg:tt)*) => {
+        if !$crate::diag::quiet() {
+            $crate::diag::print(format_args!($($arg)*));
+        }
+    }
+}
+
+#[test]
+fn report_test() {
+    take();
+
+    stat("iterations", 86usize);
+    stat("bus", "59");
+    warn("check this");
+
+    assert_eq!(Report {
+        stats: vec![("iterations".to_string(), Stat::Int(86)), ("bus".to_string(), Stat::Text("59".to_string()))],
+        warnings: vec!["check this".to_string()]
+    }, take());
+
+    assert_eq!(Report::default(), take());
+}
+
+#[test]
+fn capture_test() {
+    capture();
+
+    print(format_args!("{} ", 1));
+    debug!("two");
+    debug!();
+
+    assert_eq!("1 two\n\n", take_output());
+    assert_eq!("", take_output(), "Capture stopped");
+}
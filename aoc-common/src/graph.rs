@@ -0,0 +1,334 @@
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::fmt::{self, Write};
+
+pub type NodeId = usize;
+
+/// Edges out of or in to a node, with their weights
+type Adjacency<W> = Vec<Vec<(NodeId, W)>>;
+
+/// A directed graph of named nodes with weighted edges
+#[derive(Debug, Clone)]
+pub struct Graph<W = ()> {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    forward: Adjacency<W>,
+    reverse: Adjacency<W>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphError {
+    /// The graph contains a cycle through these nodes
+    Cycle(Vec<String>),
+    /// A calculation overflowed
+    Overflow
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphError::Cycle(names) => write!(f, "cycle {} -> {}", names.join(" -> "), names[0]),
+            GraphError::Overflow => write!(f, "arithmetic overflow")
+        }
+    }
+}
+
+impl Error for GraphError {}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Graph {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            forward: Vec::new(),
+            reverse: Vec::new()
+        }
+    }
+}
+
+impl<W: Clone> Graph<W> {
+    pub fn new() -> Graph<W> {
+        Default::default()
+    }
+
+    /// Looks up a node by name, adding it if it's new
+    pub fn node(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id
+        }
+
+        let id = self.names.len();
+
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.forward.push(Vec::new());
+        self.reverse.push(Vec::new());
+
+        id
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        self.forward[from].push((to, weight.clone()));
+        self.reverse[to].push((from, weight));
+    }
+
+    /// Looks up a node by name
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Edges out of a node
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, W)] {
+        &self.forward[id]
+    }
+
+    /// Edges in to a node
+    pub fn incoming(&self, id: NodeId) -> &[(NodeId, W)] {
+        &self.reverse[id]
+    }
+
+    fn search(adjacency: &Adjacency<W>, from: NodeId) -> Vec<NodeId> {
+        let mut seen = vec![false; adjacency.len()];
+        let mut queue: VecDeque<NodeId> = adjacency[from].iter().map(|(n, _)| *n).collect();
+        let mut result = Vec::new();
+
+        while let Some(id) = queue.pop_front() {
+            if seen[id] {
+                continue
+            }
+
+            seen[id] = true;
+            result.push(id);
+
+            queue.extend(adjacency[id].iter().map(|(n, _)| *n));
+        }
+
+        result.sort_unstable();
+
+        result
+    }
+
+    /// Nodes reachable from a node by following one or more edges
+    pub fn reachable(&self, from: NodeId) -> Vec<NodeId> {
+        Self::search(&self.forward, from)
+    }
+
+    /// Nodes which can reach a node by following one or more edges
+    pub fn ancestors(&self, to: NodeId) -> Vec<NodeId> {
+        Self::search(&self.reverse, to)
+    }
+
+    /// Finds a cycle, returning its nodes in order
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        // 0 = unvisited, 1 = on the current path, 2 = done
+        let mut state = vec![0; self.len()];
+        let mut path = Vec::new();
+
+        fn visit<W>(graph: &Graph<W>, id: NodeId, state: &mut Vec<u8>, path: &mut Vec<NodeId>) -> Option<Vec<NodeId>> {
+            state[id] = 1;
+            path.push(id);
+
+            for &(next, _) in &graph.forward[id] {
+                match state[next] {
+                    0 => {
+                        if let Some(cycle) = visit(graph, next, state, path) {
+                            return Some(cycle)
+                        }
+                    }
+                    1 => {
+                        let start = path.iter().position(|&n| n == next).unwrap();
+                        return Some(path[start..].to_vec())
+                    }
+                    _ => ()
+                }
+            }
+
+            state[id] = 2;
+            path.pop();
+
+            None
+        }
+
+        (0..self.len()).find_map(|id| {
+            if state[id] == 0 {
+                visit(self, id, &mut state, &mut path)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn cycle_error(&self) -> GraphError {
+        let cycle = self.find_cycle().unwrap_or_default();
+
+        GraphError::Cycle(cycle.iter().map(|&id| self.names[id].clone()).collect())
+    }
+
+    /// Orders the nodes so every edge leads from an earlier node to a later one
+    pub fn topo_sort(&self) -> Result<Vec<NodeId>, GraphError> {
+        let mut in_degree: Vec<usize> = self.reverse.iter().map(|e| e.len()).collect();
+        let mut ready: VecDeque<NodeId> = (0..self.len()).filter(|&id| in_degree[id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(id) = ready.pop_front() {
+            order.push(id);
+
+            for &(next, _) in &self.forward[id] {
+                in_degree[next] -= 1;
+
+                if in_degree[next] == 0 {
+                    ready.push_back(next);
+                }
+            }
+        }
+
+        if order.len() != self.len() {
+            Err(self.cycle_error())?
+        }
+
+        Ok(order)
+    }
+
+    /// Renders the graph in Graphviz DOT format, labelling edges with edge_label
+    pub fn to_dot<F>(&self, name: &str, edge_label: F) -> String
+    where F: Fn(&W) -> Option<String> {
+        let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+
+        let mut dot = String::new();
+
+        writeln!(dot, "digraph {} {{", quote(name)).unwrap();
+
+        for (id, node) in self.names.iter().enumerate() {
+            if self.forward[id].is_empty() && self.reverse[id].is_empty() {
+                writeln!(dot, "    {};", quote(node)).unwrap();
+            }
+
+            for (to, weight) in &self.forward[id] {
+                write!(dot, "    {} -> {}", quote(node), quote(&self.names[*to])).unwrap();
+
+                if let Some(label) = edge_label(weight) {
+                    write!(dot, " [label={}]", quote(&label)).unwrap();
+                }
+
+                writeln!(dot, ";").unwrap();
+            }
+        }
+
+        writeln!(dot, "}}").unwrap();
+
+        dot
+    }
+}
+
+impl<W: Copy + Into<u64>> Graph<W> {
+    /// Sum over every path leading from a node of the product of the path's
+    /// edge weights
+    pub fn path_products(&self, from: NodeId) -> Result<u64, GraphError> {
+        fn products<W: Copy + Into<u64>>(graph: &Graph<W>, id: NodeId, memo: &mut Vec<Option<u64>>,
+            on_path: &mut Vec<bool>) -> Result<u64, GraphError> {
+            if let Some(total) = memo[id] {
+                return Ok(total)
+            }
+
+            if on_path[id] {
+                Err(graph.cycle_error())?
+            }
+
+            on_path[id] = true;
+
+            let mut total: u64 = 0;
+
+            for &(next, weight) in &graph.forward[id] {
+                // Each path through next, plus the edge to next on its own
+                let paths = products(graph, next, memo, on_path)?.checked_add(1).ok_or(GraphError::Overflow)?;
+                let product = paths.checked_mul(weight.into()).ok_or(GraphError::Overflow)?;
+
+                total = total.checked_add(product).ok_or(GraphError::Overflow)?;
+            }
+
+            on_path[id] = false;
+            memo[id] = Some(total);
+
+            Ok(total)
+        }
+
+        products(self, from, &mut vec![None; self.len()], &mut vec![false; self.len()])
+    }
+}
+
+#[cfg(test)]
+fn test_graph(edges: &[(&str, &str, u16)]) -> Graph<u16> {
+    let mut graph = Graph::new();
+
+    for &(from, to, weight) in edges {
+        let from = graph.node(from);
+        let to = graph.node(to);
+
+        graph.add_edge(from, to, weight);
+    }
+
+    graph
+}
+
+#[test]
+fn reachable_test() {
+    let graph = test_graph(&[("a", "b", 1), ("b", "c", 1), ("d", "c", 1)]);
+
+    let (a, b, c, d) = (graph.id("a").unwrap(), graph.id("b").unwrap(), graph.id("c").unwrap(), graph.id("d").unwrap());
+
+    assert_eq!(4, graph.len());
+    assert_eq!(a, test_graph(&[("a", "b", 1)]).node("a"));
+    assert_eq!(vec![b, c], graph.reachable(a));
+    assert_eq!(vec![a, b, d], graph.ancestors(c));
+    assert_eq!("c", graph.name(graph.edges(d)[0].0));
+    assert_eq!(2, graph.incoming(c).len());
+}
+
+#[test]
+fn topo_sort_test() {
+    let graph = test_graph(&[("shirt", "tie", 1), ("tie", "jacket", 1), ("trousers", "shoes", 1), ("trousers", "jacket", 1)]);
+
+    let order = graph.topo_sort().unwrap();
+    let pos = |name| order.iter().position(|&id| graph.name(id) == name).unwrap();
+
+    assert!(pos("shirt") < pos("tie") && pos("tie") < pos("jacket"));
+    assert!(pos("trousers") < pos("shoes") && pos("trousers") < pos("jacket"));
+    assert_eq!(None, graph.find_cycle());
+
+    let graph = test_graph(&[("a", "b", 1), ("b", "c", 1), ("c", "b", 1)]);
+
+    assert_eq!(Some(vec![1, 2]), graph.find_cycle());
+    assert_eq!("cycle b -> c -> b", graph.topo_sort().unwrap_err().to_string());
+    assert!(graph.path_products(0).is_err(), "Cycles have infinite paths");
+}
+
+#[test]
+fn path_products_test() {
+    // 2 b, 2 * 3 c directly inside b and 4 c directly inside a
+    let graph = test_graph(&[("a", "b", 2), ("b", "c", 3), ("a", "c", 4)]);
+
+    assert_eq!(Ok(12), graph.path_products(0));
+    assert_eq!(Ok(0), graph.path_products(2));
+}
+
+#[test]
+fn dot_test() {
+    let mut graph = test_graph(&[("a", "b \"q\"", 2)]);
+    graph.node("c");
+
+    assert_eq!("digraph \"g\" {\n    \"a\" -> \"b \\\"q\\\"\" [label=\"2\"];\n    \"c\";\n}\n",
+        graph.to_dot("g", |w| Some(w.to_string())));
+}
@@ -0,0 +1,400 @@
+use std::io::BufRead;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{self, ParseError};
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// The 4 orthogonal directions as (x, y) offsets
+pub const DIRS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// The 8 orthogonal and diagonal directions as (x, y) offsets
+pub const DIRS8: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0), (1, 0),
+    (-1, 1), (0, 1), (1, 1)
+];
+
+/// A rectangular grid of cells indexed by (x, y), with y = 0 the top row
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid filling each cell from its position
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Grid<T>
+    where F: FnMut(usize, usize) -> T {
+        let mut cells = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(cell(x, y));
+            }
+        }
+
+        Grid { width, height, cells }
+    }
+
+    /// Builds a grid from rows of cells. Returns None if the rows differ in length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+
+        if rows.iter().any(|r| r.len() != width) {
+            return None
+        }
+
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect()
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn offset(&self, x: isize, y: isize) -> Option<usize> {
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            None
+        } else {
+            Some(y as usize * self.width + x as usize)
+        }
+    }
+
+    /// Bounds-checked access to a cell
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.offset(x, y).map(|o| &self.cells[o])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.offset(x, y).map(move |o| &mut self.cells[o])
+    }
+
+    /// Access to a cell with the coordinates wrapping round at the edges
+    pub fn get_wrapped(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize);
+        let y = y.rem_euclid(self.height as isize);
+
+        &self.cells[y as usize * self.width + x as usize]
+    }
+
+    /// Iterates the rows of the grid as slices
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+
+        self.cells.iter().enumerate().map(move |(i, c)| ((i % width, i / width), c))
+    }
+
+    /// Counts the cells matching a predicate
+    pub fn count<F>(&self, pred: F) -> usize
+    where F: Fn(&T) -> bool {
+        self.cells.iter().filter(|c| pred(c)).count()
+    }
+
+    /// Builds a new grid of the same size by mapping each cell
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where F: Fn(&T) -> U {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect()
+        }
+    }
+
+    fn in_dirs<'a>(&'a self, x: usize, y: usize, dirs: &'a [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        dirs.iter().filter_map(move |&(dx, dy)| {
+            let (nx, ny) = (x as isize + dx, y as isize + dy);
+
+            self.offset(nx, ny).map(|_| (nx as usize, ny as usize))
+        })
+    }
+
+    /// Positions of the orthogonally adjacent cells
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.in_dirs(x, y, &DIRS4)
+    }
+
+    /// Positions of the orthogonally and diagonally adjacent cells
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.in_dirs(x, y, &DIRS8)
+    }
+
+    /// Positions of the first cell in each of the 8 directions for which visible
+    /// returns true, looking past any others
+    pub fn line_of_sight<'a, F>(&'a self, x: usize, y: usize, visible: F) -> impl Iterator<Item = (usize, usize)> + 'a
+    where F: Fn(&T) -> bool + 'a {
+        DIRS8.iter().filter_map(move |&(dx, dy)| {
+            let (mut cx, mut cy) = (x as isize, y as isize);
+
+            loop {
+                cx += dx;
+                cy += dy;
+
+                let cell = self.get(cx, cy)?;
+
+                if visible(cell) {
+                    break Some((cx as usize, cy as usize))
+                }
+            }
+        })
+    }
+
+    /// Renders the grid as text, one line per row
+    pub fn render<F>(&self, to_char: F) -> String
+    where F: Fn(&T) -> char {
+        let mut result = String::with_capacity((self.width + 1) * self.height);
+
+        for row in self.rows().take(self.height) {
+            result.extend(row.iter().map(&to_char));
+            result.push('\n');
+        }
+
+        result
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Builds a grid with every cell set to fill
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height]
+        }
+    }
+
+    fn remap<F>(&self, width: usize, height: usize, from: F) -> Grid<T>
+    where F: Fn(usize, usize) -> (usize, usize) {
+        Grid::from_fn(width, height, |x, y| {
+            let (fx, fy) = from(x, y);
+            self.cells[fy * self.width + fx].clone()
+        })
+    }
+
+    /// Rotates the grid 90 degrees clockwise
+    pub fn rotate_cw(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    /// Rotates the grid 90 degrees anticlockwise
+    pub fn rotate_ccw(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    /// Mirrors the grid left to right
+    pub fn flip_horiz(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+
+    /// Mirrors the grid top to bottom
+    pub fn flip_vert(&self) -> Grid<T> {
+        self.remap(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+
+    /// Swaps rows and columns
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// The 8 distinct rotations and reflections of the grid, starting with itself
+    pub fn orientations(&self) -> Vec<Grid<T>> {
+        let mut result = Vec::with_capacity(8);
+
+        let mut grid = self.clone();
+
+        for _ in 0..4 {
+            let flipped = grid.flip_horiz();
+            let next = grid.rotate_cw();
+
+            result.push(grid);
+            result.push(flipped);
+
+            grid = next;
+        }
+
+        result
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// Parses a grid with one row per line, converting each character with cell.
+/// expected describes the valid characters for error messages
+pub fn parse<T, F>(input: &mut dyn BufRead, cell: F, expected: &str) -> Result<Grid<T>, ParseError>
+where F: Fn(char) -> Option<T> {
+    let mut width = None;
+    let mut height = 0;
+    let mut cells = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let mut row_width = 0;
+
+        for (i, c) in line.text.char_indices() {
+            match cell(c) {
+                Some(value) => cells.push(value),
+                None => Err(line.unexpected(&line.text[i..i + c.len_utf8()], expected))?
+            }
+
+            row_width += 1;
+        }
+
+        match width {
+            None => width = Some(row_width),
+            Some(width) if row_width < width => Err(line.missing(expected))?,
+            Some(width) if row_width > width => {
+                let (i, _) = line.text.char_indices().nth(width).unwrap();
+                Err(line.invalid(&line.text[i..], &format!("rows must be {} long", width)))?
+            }
+            _ => ()
+        }
+
+        height += 1;
+    }
+
+    Ok(Grid {
+        width: width.unwrap_or(0),
+        height,
+        cells
+    })
+}
+
+/// Cell conversion for '#' / '.' maps
+pub fn hash_dot(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    }
+}
+
+/// Renders a '#' / '.' map cell
+pub fn to_hash_dot(cell: &bool) -> char {
+    if *cell { '#' } else { '.' }
+}
+
+#[test]
+fn parse_test() {
+    let grid = parse(&mut "#..\n.#.\n".as_bytes(), hash_dot, "'#' or '.'").unwrap();
+
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    assert!(grid[(1, 1)]);
+    assert_eq!("#..\n.#.\n", grid.render(to_hash_dot));
+
+    assert_eq!("line 2 column 2: unexpected 'x', expecting '#' or '.'",
+        parse(&mut "#..\n.x.\n".as_bytes(), hash_dot, "'#' or '.'").unwrap_err().to_string());
+    assert_eq!("line 2 column 3: missing '#' or '.'",
+        parse(&mut "#..\n.#\n".as_bytes(), hash_dot, "'#' or '.'").unwrap_err().to_string());
+    assert_eq!("line 2 column 4: invalid '#': rows must be 3 long",
+        parse(&mut "#..\n.#.#\n".as_bytes(), hash_dot, "'#' or '.'").unwrap_err().to_string());
+}
+
+#[test]
+fn access_test() {
+    let grid = Grid::from_fn(3, 2, |x, y| y * 3 + x);
+
+    assert_eq!(Some(&4), grid.get(1, 1));
+    assert_eq!(None, grid.get(3, 0));
+    assert_eq!(None, grid.get(0, -1));
+    assert_eq!(&2, grid.get_wrapped(-1, 2));
+    assert_eq!(&3, grid.get_wrapped(6, 7));
+    assert_eq!(None, Grid::from_rows(vec![vec![1, 2], vec![3]]));
+}
+
+#[test]
+fn neighbours_test() {
+    let grid = Grid::new(3, 3, 0);
+
+    assert_eq!(vec![(1, 0), (0, 1)], grid.neighbours4(0, 0).collect::<Vec<_>>());
+    assert_eq!(8, grid.neighbours8(1, 1).count());
+    assert_eq!(3, grid.neighbours8(2, 2).count());
+
+    let grid = Grid::from_rows(vec![
+        vec![1, 0, 0, 1],
+        vec![0, 0, 0, 0],
+        vec![0, 0, 1, 1]
+    ]).unwrap();
+
+    assert_eq!(vec![(3, 0), (2, 2)], grid.line_of_sight(0, 0, |&c| c == 1).collect::<Vec<_>>());
+}
+
+#[test]
+fn orient_test() {
+    let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+
+    assert_eq!(Grid::from_rows(vec![vec![4, 1], vec![5, 2], vec![6, 3]]).unwrap(), grid.rotate_cw());
+    assert_eq!(Grid::from_rows(vec![vec![3, 6], vec![2, 5], vec![1, 4]]).unwrap(), grid.rotate_ccw());
+    assert_eq!(Grid::from_rows(vec![vec![3, 2, 1], vec![6, 5, 4]]).unwrap(), grid.flip_horiz());
+    assert_eq!(Grid::from_rows(vec![vec![4, 5, 6], vec![1, 2, 3]]).unwrap(), grid.flip_vert());
+    assert_eq!(Grid::from_rows(vec![vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap(), grid.transpose());
+
+    let orientations = grid.orientations();
+
+    assert_eq!(8, orientations.len());
+    assert!(orientations.contains(&grid.transpose()));
+    assert!(orientations.contains(&grid.flip_vert()));
+}
+
+#[cfg(test)]
+fn any_grid() -> impl Strategy<Value = Grid<u8>> {
+    (1..8usize, 1..8usize).prop_flat_map(|(w, h)| {
+        prop::collection::vec(any::<u8>(), w * h).prop_map(move |cells| Grid { width: w, height: h, cells })
+    })
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn rotate_prop(grid in any_grid()) {
+        let mut rotated = grid.clone();
+
+        for _ in 0..4 {
+            rotated = rotated.rotate_cw();
+        }
+
+        prop_assert_eq!(&grid, &rotated);
+        prop_assert_eq!(&grid, &grid.rotate_cw().rotate_ccw());
+    }
+
+    #[test]
+    fn flip_prop(grid in any_grid()) {
+        prop_assert_eq!(&grid, &grid.flip_horiz().flip_horiz());
+        prop_assert_eq!(&grid, &grid.flip_vert().flip_vert());
+        prop_assert_eq!(&grid, &grid.transpose().transpose());
+
+        // Both flips together are a half turn
+        prop_assert_eq!(grid.rotate_cw().rotate_cw(), grid.flip_horiz().flip_vert());
+    }
+}
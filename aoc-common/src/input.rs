@@ -0,0 +1,198 @@
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding a directory containing inputNN.txt files
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to read a day's puzzle input from
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    Search(Vec<PathBuf>)
+}
+
+/// The puzzle input for a day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Input {
+    pub day: u8,
+    pub source: InputSource
+}
+
+impl Input {
+    /// Resolves the input from an optional path argument, falling back to the environment
+    pub fn new(day: u8, arg: Option<&str>) -> Input {
+        Input {
+            day,
+            source: InputSource::resolve(day, arg, |var| env::var(var).ok())
+        }
+    }
+
+    /// Resolves the input from the first command line argument
+    pub fn from_args(day: u8) -> Input {
+        let arg = env::args().nth(1);
+
+        Input::new(day, arg.as_deref())
+    }
+
+    /// Opens the input for buffered reading, returning its name for error reporting
+    pub fn open(&self) -> Result<(String, Box<dyn BufRead>), InputError> {
+        match &self.source {
+            InputSource::Stdin => Ok(("stdin".to_string(), Box::new(io::BufReader::new(io::stdin())))),
+            InputSource::File(path) => self.open_file(path),
+            InputSource::Search(paths) => {
+                match paths.iter().find(|p| p.is_file()) {
+                    Some(path) => self.open_file(path),
+                    None => Err(InputError::NotFound { day: self.day, paths: paths.clone() })
+                }
+            }
+        }
+    }
+
+    /// The input's file: the first of the search paths which exists, or the
+    /// first if none do yet. None for stdin
+    pub fn path(&self) -> Option<PathBuf> {
+        match &self.source {
+            InputSource::Stdin => None,
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Search(paths) => paths.iter().find(|p| p.is_file()).or_else(|| paths.first()).cloned()
+        }
+    }
+
+    fn open_file(&self, path: &Path) -> Result<(String, Box<dyn BufRead>), InputError> {
+        match fs::File::open(path) {
+            Ok(file) => Ok((path.display().to_string(), Box::new(io::BufReader::new(file)))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                Err(InputError::NotFound { day: self.day, paths: vec![path.to_path_buf()] })
+            }
+            Err(e) => Err(InputError::Io { day: self.day, path: path.to_path_buf(), error: e })
+        }
+    }
+}
+
+impl InputSource {
+    /// Works out the input source. In order of precedence:
+    ///   the path argument ("-" for stdin),
+    ///   the AOC_INPUT_NN environment variable,
+    ///   inputNN.txt in the directory named by AOC_INPUT_DIR,
+    ///   inputNN.txt in the current or parent directory
+    pub fn resolve<F>(day: u8, arg: Option<&str>, env_var: F) -> InputSource
+    where F: Fn(&str) -> Option<String> {
+        let file_name = default_file_name(day);
+
+        let explicit = arg.map(|a| a.to_string()).or_else(|| env_var(&day_var(day)));
+
+        match explicit {
+            Some(path) if path == "-" => InputSource::Stdin,
+            Some(path) => InputSource::File(PathBuf::from(path)),
+            None => match env_var(INPUT_DIR_VAR) {
+                Some(dir) => InputSource::File(Path::new(&dir).join(&file_name)),
+                None => InputSource::Search(vec![
+                    PathBuf::from(&file_name),
+                    Path::new("..").join(&file_name)
+                ])
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Search(paths) => write!(f, "{}", join_paths(paths))
+        }
+    }
+}
+
+/// Environment variable overriding the input file for a day
+pub fn day_var(day: u8) -> String {
+    format!("AOC_INPUT_{:02}", day)
+}
+
+fn default_file_name(day: u8) -> String {
+    format!("input{:02}.txt", day)
+}
+
+fn join_paths(paths: &[PathBuf]) -> String {
+    paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ")
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound { day: u8, paths: Vec<PathBuf> },
+    Io { day: u8, path: PathBuf, error: io::Error }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound { day, paths } => {
+                write!(f, "Day {}: input file not found (tried {})", day, join_paths(paths))
+            }
+            InputError::Io { day, path, error } => {
+                write!(f, "Day {}: unable to open input file {}: {}", day, path.display(), error)
+            }
+        }
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            InputError::NotFound { .. } => None,
+            InputError::Io { error, .. } => Some(error)
+        }
+    }
+}
+
+#[test]
+fn resolve_test() {
+    let no_env = |_: &str| None;
+
+    assert_eq!(InputSource::File(PathBuf::from("my.txt")), InputSource::resolve(7, Some("my.txt"), no_env));
+    assert_eq!(InputSource::Stdin, InputSource::resolve(7, Some("-"), no_env));
+    assert_eq!(
+        InputSource::Search(vec![PathBuf::from("input07.txt"), PathBuf::from("../input07.txt")]),
+        InputSource::resolve(7, None, no_env)
+    );
+
+    let env = |var: &str| match var {
+        "AOC_INPUT_07" => Some("day7.txt".to_string()),
+        "AOC_INPUT_DIR" => Some("/inputs".to_string()),
+        _ => None
+    };
+
+    assert_eq!(InputSource::File(PathBuf::from("my.txt")), InputSource::resolve(7, Some("my.txt"), env));
+    assert_eq!(InputSource::File(PathBuf::from("day7.txt")), InputSource::resolve(7, None, env));
+    assert_eq!(InputSource::File(PathBuf::from("/inputs/input08.txt")), InputSource::resolve(8, None, env));
+}
+
+#[test]
+fn path_test() {
+    let input = |source| Input { day: 7, source };
+
+    assert_eq!(None, input(InputSource::Stdin).path());
+    assert_eq!(Some(PathBuf::from("my.txt")), input(InputSource::File(PathBuf::from("my.txt"))).path());
+    assert_eq!(Some(PathBuf::from("../Cargo.toml")),
+        input(InputSource::Search(vec![PathBuf::from("no-such-input07.txt"), PathBuf::from("../Cargo.toml")])).path());
+    assert_eq!(Some(PathBuf::from("no-such-input07.txt")), input(InputSource::Search(vec![PathBuf::from("no-such-input07.txt")])).path());
+}
+
+#[test]
+fn not_found_test() {
+    let input = Input {
+        day: 7,
+        source: InputSource::Search(vec![PathBuf::from("no-such-input07.txt"), PathBuf::from("../no-such-input07.txt")])
+    };
+
+    match input.open() {
+        Err(e) => assert_eq!("Day 7: input file not found (tried no-such-input07.txt, ../no-such-input07.txt)", e.to_string()),
+        Ok(_) => panic!("Input should not be found")
+    }
+}
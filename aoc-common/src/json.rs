@@ -0,0 +1,92 @@
+use std::fmt;
+
+/// A JSON value, for writing machine-readable output
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Int(i128),
+    Float(f64),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>)
+}
+
+impl Json {
+    /// Builds an object from name and value pairs
+    pub fn object(members: Vec<(&str, Json)>) -> Json {
+        Json::Object(members.into_iter().map(|(n, v)| (n.to_string(), v)).collect())
+    }
+
+    pub fn str(s: &str) -> Json {
+        Json::Str(s.to_string())
+    }
+}
+
+fn write_str(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?
+        }
+    }
+
+    write!(f, "\"")
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{}", b),
+            Json::Int(n) => write!(f, "{}", n),
+            Json::Float(n) if n.is_finite() => write!(f, "{}", n),
+            Json::Float(_) => write!(f, "null"),
+            Json::Str(s) => write_str(f, s),
+            Json::Array(values) => {
+                write!(f, "[")?;
+
+                for (i, v) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", v)?;
+                }
+
+                write!(f, "]")
+            }
+            Json::Object(members) => {
+                write!(f, "{{")?;
+
+                for (i, (name, v)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_str(f, name)?;
+                    write!(f, ":{}", v)?;
+                }
+
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+#[test]
+fn json_test() {
+    let json = Json::object(vec![
+        ("day", Json::Int(21)),
+        ("answer", Json::str("a \"b\"\n\\")),
+        ("parts", Json::Array(vec![Json::Null, Json::Bool(true), Json::Float(1.5)])),
+        ("stats", Json::Object(vec![]))
+    ]);
+
+    assert_eq!(r#"{"day":21,"answer":"a \"b\"\n\\","parts":[null,true,1.5],"stats":{}}"#, json.to_string());
+}
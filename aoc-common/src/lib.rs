@@ -0,0 +1,43 @@
+use std::process;
+
+pub mod answers;
+pub mod bench;
+pub mod ca;
+pub mod diag;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod matching;
+pub mod numtheory;
+pub mod parallel;
+pub mod parse;
+pub mod solution;
+pub mod watch;
+
+pub use diag::{stat, warn};
+pub use grid::Grid;
+pub use input::Input;
+pub use json::Json;
+pub use parse::{ErrorKind, Line, ParseError};
+pub use solution::{Answer, Day, Solution};
+
+/// Runs a day's solution from its own binary, taking the input from the command line
+pub fn main<S: Solution>() {
+    if let Err(e) = run::<S>() {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run<S: Solution>() -> Result<(), Box<dyn std::error::Error>> {
+    let input = Input::from_args(S::DAY);
+
+    let parts: Vec<u8> = (1..=S::PARTS).collect();
+
+    for answer in Day::of::<S>().run_input(&input, &parts)? {
+        println!("Part {}: {}", answer.part, answer.value);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use std::collections::VecDeque;
+
+/// Result of assigning each left item a different right item
+#[derive(Debug, Clone, PartialEq)]
+pub enum Assignment {
+    /// There is exactly one way to assign every left item
+    Unique(Vec<usize>),
+    /// Every left item can be assigned, but not in only one way. options
+    /// lists every right item each left item can take in some assignment
+    Ambiguous { matching: Vec<usize>, options: Vec<Vec<usize>> },
+    /// Some left items can't be assigned. Holds a maximum matching, with
+    /// None for the left items left out
+    Impossible(Vec<Option<usize>>)
+}
+
+/// Maximum bipartite matching by Hopcroft-Karp. candidates lists the right
+/// items (0..right) each left item may be matched with. Returns the right item
+/// matched with each left item
+pub fn max_matching(candidates: &[Vec<usize>], right: usize) -> Vec<Option<usize>> {
+    let mut match_left: Vec<Option<usize>> = vec![None; candidates.len()];
+    let mut match_right: Vec<Option<usize>> = vec![None; right];
+
+    loop {
+        // Breadth first search for the layers of shortest augmenting paths
+        let mut dist: Vec<Option<usize>> = match_left.iter().map(|m| if m.is_none() { Some(0) } else { None }).collect();
+        let mut queue: VecDeque<usize> = (0..candidates.len()).filter(|&l| match_left[l].is_none()).collect();
+        let mut found = false;
+
+        while let Some(l) = queue.pop_front() {
+            for &r in &candidates[l] {
+                match match_right[r] {
+                    None => found = true,
+                    Some(next) => if dist[next].is_none() {
+                        dist[next] = Some(dist[l].unwrap() + 1);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        if !found {
+            break
+        }
+
+        // Depth first search along the layers, augmenting vertex disjoint paths
+        fn augment(l: usize, candidates: &[Vec<usize>], dist: &mut Vec<Option<usize>>,
+            match_left: &mut Vec<Option<usize>>, match_right: &mut Vec<Option<usize>>) -> bool {
+            let next_dist = dist[l].map(|d| d + 1);
+
+            for &r in &candidates[l] {
+                let ok = match match_right[r] {
+                    None => true,
+                    Some(next) => next_dist.is_some() && dist[next] == next_dist
+                        && augment(next, candidates, dist, match_left, match_right)
+                };
+
+                if ok {
+                    match_left[l] = Some(r);
+                    match_right[r] = Some(l);
+                    return true
+                }
+            }
+
+            // Dead end, don't try again this phase
+            dist[l] = None;
+
+            false
+        }
+
+        for l in 0..candidates.len() {
+            if match_left[l].is_none() {
+                augment(l, candidates, &mut dist, &mut match_left, &mut match_right);
+            }
+        }
+    }
+
+    match_left
+}
+
+/// Looks for an augmenting path from l, skipping right items already visited
+fn reassign(l: usize, candidates: &[Vec<usize>], visited: &mut Vec<bool>,
+    match_left: &mut Vec<Option<usize>>, match_right: &mut Vec<Option<usize>>) -> bool {
+    for &r in &candidates[l] {
+        if visited[r] {
+            continue
+        }
+
+        visited[r] = true;
+
+        let ok = match match_right[r] {
+            None => true,
+            Some(next) => reassign(next, candidates, visited, match_left, match_right)
+        };
+
+        if ok {
+            match_left[l] = Some(r);
+            match_right[r] = Some(l);
+            return true
+        }
+    }
+
+    false
+}
+
+/// Assigns each left item a different right item from its candidates, and
+/// reports whether that can be done in exactly one way
+pub fn assign(candidates: &[Vec<usize>], right: usize) -> Assignment {
+    let matching = max_matching(candidates, right);
+
+    if matching.iter().any(|m| m.is_none()) {
+        return Assignment::Impossible(matching)
+    }
+
+    let matching: Vec<usize> = matching.into_iter().map(|m| m.unwrap()).collect();
+
+    let mut match_right = vec![None; right];
+
+    for (l, &r) in matching.iter().enumerate() {
+        match_right[r] = Some(l);
+    }
+
+    // Try giving each left item each of its other candidates, then finding a
+    // new partner for any left item that displaces
+    let options: Vec<Vec<usize>> = candidates.iter().enumerate().map(|(l, cands)| {
+        let mut options: Vec<usize> = cands.iter().copied().filter(|&r| {
+            if r == matching[l] {
+                return true
+            }
+
+            let mut match_left: Vec<Option<usize>> = matching.iter().map(|&r| Some(r)).collect();
+            let mut match_right = match_right.clone();
+
+            let displaced = match_right[r];
+
+            match_right[matching[l]] = None;
+            match_left[l] = Some(r);
+            match_right[r] = Some(l);
+
+            match displaced {
+                None => true,
+                Some(other) => {
+                    match_left[other] = None;
+
+                    let mut visited = vec![false; right];
+                    visited[r] = true;
+
+                    reassign(other, candidates, &mut visited, &mut match_left, &mut match_right)
+                }
+            }
+        }).collect();
+
+        options.sort_unstable();
+        options.dedup();
+
+        options
+    }).collect();
+
+    if options.iter().all(|o| o.len() == 1) {
+        Assignment::Unique(matching)
+    } else {
+        Assignment::Ambiguous { matching, options }
+    }
+}
+
+#[test]
+fn max_matching_test() {
+    // Greedy matching 0-0 blocks 1, needs an augmenting path
+    assert_eq!(vec![Some(1), Some(0)], max_matching(&[vec![0, 1], vec![0]], 2));
+
+    assert_eq!(2, max_matching(&[vec![0], vec![0], vec![1]], 2).iter().filter(|m| m.is_some()).count());
+    assert_eq!(Vec::<Option<usize>>::new(), max_matching(&[], 3));
+}
+
+#[test]
+fn assign_test() {
+    // Day 16 example: elimination leaves one choice each
+    assert_eq!(Assignment::Unique(vec![1, 0, 2]), assign(&[vec![1], vec![0, 1], vec![0, 1, 2]], 3));
+
+    // Left items 0 and 1 can swap, 2 is fixed
+    assert_eq!(Assignment::Ambiguous {
+        matching: vec![0, 1, 2],
+        options: vec![vec![0, 1], vec![0, 1], vec![2]]
+    }, assign(&[vec![0, 1], vec![0, 1], vec![2]], 3));
+
+    // Two candidates for 0 but only one works
+    match assign(&[vec![0, 1], vec![1]], 2) {
+        Assignment::Unique(m) => assert_eq!(vec![0, 1], m),
+        other => panic!("Expected unique, got {:?}", other)
+    }
+
+    // 0 can move to the unused right item 2
+    match assign(&[vec![0, 2], vec![1]], 3) {
+        Assignment::Ambiguous { options, .. } => assert_eq!(vec![vec![0, 2], vec![1]], options),
+        other => panic!("Expected ambiguous, got {:?}", other)
+    }
+
+    assert!(matches!(assign(&[vec![0], vec![0]], 1), Assignment::Impossible(_)));
+}
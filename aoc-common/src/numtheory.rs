@@ -0,0 +1,356 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// Signed integer types usable with the number theory functions
+pub trait Int: Copy + Ord + Hash + fmt::Debug + fmt::Display
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+    + Div<Output = Self> + Rem<Output = Self> + Neg<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    const TWO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    fn rem_euclid(self, other: Self) -> Self;
+}
+
+macro_rules! int_impl {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+            const TWO: $t = 2;
+
+            fn checked_add(self, other: $t) -> Option<$t> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: $t) -> Option<$t> {
+                <$t>::checked_sub(self, other)
+            }
+
+            fn checked_mul(self, other: $t) -> Option<$t> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_div(self, other: $t) -> Option<$t> {
+                <$t>::checked_div(self, other)
+            }
+
+            fn checked_neg(self) -> Option<$t> {
+                <$t>::checked_neg(self)
+            }
+
+            fn rem_euclid(self, other: $t) -> $t {
+                <$t>::rem_euclid(self, other)
+            }
+        })*
+    }
+}
+
+int_impl!(i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumError {
+    /// A calculation overflowed the integer type
+    Overflow,
+    /// Modulus must be positive
+    BadModulus,
+    /// The number shares a factor with the modulus
+    NoInverse,
+    /// The equations have no solution
+    NoSolution
+}
+
+impl fmt::Display for NumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NumError::Overflow => write!(f, "arithmetic overflow"),
+            NumError::BadModulus => write!(f, "modulus must be positive"),
+            NumError::NoInverse => write!(f, "no modular inverse"),
+            NumError::NoSolution => write!(f, "no solution")
+        }
+    }
+}
+
+impl Error for NumError {}
+
+fn add<T: Int>(a: T, b: T) -> Result<T, NumError> {
+    a.checked_add(b).ok_or(NumError::Overflow)
+}
+
+fn sub<T: Int>(a: T, b: T) -> Result<T, NumError> {
+    a.checked_sub(b).ok_or(NumError::Overflow)
+}
+
+fn mul<T: Int>(a: T, b: T) -> Result<T, NumError> {
+    a.checked_mul(b).ok_or(NumError::Overflow)
+}
+
+fn div<T: Int>(a: T, b: T) -> Result<T, NumError> {
+    a.checked_div(b).ok_or(NumError::Overflow)
+}
+
+fn neg<T: Int>(a: T) -> Result<T, NumError> {
+    a.checked_neg().ok_or(NumError::Overflow)
+}
+
+fn check_modulus<T: Int>(m: T) -> Result<(), NumError> {
+    if m <= T::ZERO {
+        Err(NumError::BadModulus)
+    } else {
+        Ok(())
+    }
+}
+
+/// Extended Euclidean algorithm. Returns (g, x, y) where g is the non-negative
+/// greatest common divisor of a and b and ax + by = g
+pub fn egcd<T: Int>(a: T, b: T) -> Result<(T, T, T), NumError> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let q = div(old_r, r)?;
+
+        let next_r = sub(old_r, mul(q, r)?)?;
+        old_r = r;
+        r = next_r;
+
+        let next_x = sub(old_x, mul(q, x)?)?;
+        old_x = x;
+        x = next_x;
+
+        let next_y = sub(old_y, mul(q, y)?)?;
+        old_y = y;
+        y = next_y;
+    }
+
+    if old_r < T::ZERO {
+        Ok((neg(old_r)?, neg(old_x)?, neg(old_y)?))
+    } else {
+        Ok((old_r, old_x, old_y))
+    }
+}
+
+/// Multiplies a by b modulo m, the result in 0..m
+pub fn modmul<T: Int>(a: T, b: T, m: T) -> Result<T, NumError> {
+    check_modulus(m)?;
+
+    Ok(mul(a.rem_euclid(m), b.rem_euclid(m))?.rem_euclid(m))
+}
+
+/// The x in 0..m where ax = 1 modulo m
+pub fn modinv<T: Int>(a: T, m: T) -> Result<T, NumError> {
+    check_modulus(m)?;
+
+    let (g, x, _) = egcd(a.rem_euclid(m), m)?;
+
+    if g != T::ONE {
+        Err(NumError::NoInverse)?
+    }
+
+    Ok(x.rem_euclid(m))
+}
+
+/// Raises base to the power exp modulo m by repeated squaring. A negative
+/// exponent raises the inverse of base
+pub fn modpow<T: Int>(base: T, exp: T, m: T) -> Result<T, NumError> {
+    check_modulus(m)?;
+
+    let (mut base, mut exp) = if exp < T::ZERO {
+        (modinv(base, m)?, neg(exp)?)
+    } else {
+        (base.rem_euclid(m), exp)
+    };
+
+    let mut result = T::ONE.rem_euclid(m);
+
+    while exp > T::ZERO {
+        if exp % T::TWO == T::ONE {
+            result = modmul(result, base, m)?;
+        }
+
+        base = modmul(base, base, m)?;
+        exp = exp / T::TWO;
+    }
+
+    Ok(result)
+}
+
+/// Generalised Chinese remainder theorem. Solves x = residue modulo modulus
+/// for each (residue, modulus) pair, the moduli need not be coprime. Returns
+/// the smallest non-negative x and the lowest common multiple of the moduli
+pub fn crt<T: Int>(equations: &[(T, T)]) -> Result<(T, T), NumError> {
+    let mut x = T::ZERO;
+    let mut lcm = T::ONE;
+
+    for &(residue, modulus) in equations {
+        check_modulus(modulus)?;
+
+        // Find k where x + k * lcm = residue (mod modulus)
+        let (g, p, _) = egcd(lcm, modulus)?;
+
+        let diff = sub(residue.rem_euclid(modulus), x.rem_euclid(modulus))?;
+
+        if diff % g != T::ZERO {
+            Err(NumError::NoSolution)?
+        }
+
+        let step = modulus / g;
+        let k = modmul(diff / g, p, step)?;
+
+        let next_lcm = mul(lcm, step)?;
+
+        x = add(x, mul(lcm, k)?)?.rem_euclid(next_lcm);
+        lcm = next_lcm;
+    }
+
+    Ok((x, lcm))
+}
+
+/// Smallest n where n * n >= a, for non-negative a
+fn ceil_sqrt<T: Int>(a: T) -> T {
+    let (mut lo, mut hi) = (T::ZERO, a);
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / T::TWO;
+
+        // Overflowing squares are too big
+        match mid.checked_mul(mid) {
+            Some(sq) if sq < a => lo = mid + T::ONE,
+            _ => hi = mid
+        }
+    }
+
+    lo
+}
+
+/// Discrete logarithm by baby-step giant-step. Returns the smallest non-negative x
+/// where base^x = target modulo m. base must be coprime with m
+pub fn discrete_log<T: Int>(base: T, target: T, m: T) -> Result<T, NumError> {
+    check_modulus(m)?;
+
+    let target = target.rem_euclid(m);
+    let n = ceil_sqrt(m);
+
+    // Baby steps: base^j for j in 0..n, keeping the smallest j
+    let mut steps = HashMap::new();
+    let mut power = T::ONE.rem_euclid(m);
+    let mut j = T::ZERO;
+
+    while j < n {
+        steps.entry(power).or_insert(j);
+
+        power = modmul(power, base, m)?;
+        j = add(j, T::ONE)?;
+    }
+
+    // Giant steps: target * base^-(i * n)
+    let giant = modinv(modpow(base, n, m)?, m)?;
+    let mut gamma = target;
+    let mut i = T::ZERO;
+
+    while i < n {
+        if let Some(&j) = steps.get(&gamma) {
+            return add(mul(i, n)?, j)
+        }
+
+        gamma = modmul(gamma, giant, m)?;
+        i = add(i, T::ONE)?;
+    }
+
+    Err(NumError::NoSolution)
+}
+
+#[test]
+fn egcd_test() {
+    assert_eq!(Ok((2, -9, 47)), egcd(240, 46));
+    assert_eq!(Ok((6, 0, 1)), egcd(0, 6));
+    assert_eq!(Ok((2, -1, 0)), egcd(-2, 0));
+    assert_eq!(Err(NumError::Overflow), egcd(i64::MIN, -1));
+
+    let (g, x, y) = egcd(1_234_567_i64, 7_654_321).unwrap();
+    assert_eq!(g, 1_234_567 * x + 7_654_321 * y);
+}
+
+#[test]
+fn modinv_test() {
+    assert_eq!(Ok(4), modinv(3, 11));
+    assert_eq!(Ok(7), modinv(-3, 11));
+    assert_eq!(Err(NumError::NoInverse), modinv(4, 8));
+    assert_eq!(Err(NumError::BadModulus), modinv(3, 0));
+}
+
+#[test]
+fn modpow_test() {
+    assert_eq!(Ok(445), modpow(4, 13, 497));
+    assert_eq!(Ok(0), modpow(5, 0, 1));
+    assert_eq!(Ok(4), modpow(3, -1, 11));
+    assert_eq!(Err(NumError::Overflow), modpow(2_i64, i64::MIN, 7));
+    assert_eq!(Ok(14897079), modpow(17807724_i64, 8, 20201227));
+
+    // Squaring overflows i32 with a large modulus, fine in i64
+    assert_eq!(Err(NumError::Overflow), modpow(123_456_i32, 3, 2_000_000_011));
+    assert_eq!(Ok(284_853_796), modpow(123_456_i64, 3, 2_000_000_011));
+}
+
+#[test]
+fn crt_test() {
+    assert_eq!(Ok((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+
+    // Moduli with common factors
+    assert_eq!(Ok((10, 12)), crt(&[(4, 6), (2, 4)]));
+    assert_eq!(Err(NumError::NoSolution), crt(&[(1, 6), (2, 4)]));
+
+    assert_eq!(Ok((0, 1)), crt::<i64>(&[]));
+    assert_eq!(Ok((1068781_i64, 3162341)), crt(&[(0, 7), (-1, 13), (-4, 59), (-6, 31), (-7, 19)]));
+}
+
+#[test]
+fn discrete_log_test() {
+    assert_eq!(Ok(8), discrete_log(7, 5764801, 20201227_i64));
+    assert_eq!(Ok(11), discrete_log(7, 17807724, 20201227_i64));
+    assert_eq!(Ok(0), discrete_log(3, 1, 7));
+    assert_eq!(Err(NumError::NoSolution), discrete_log(2, 3, 7));
+    assert_eq!(3, ceil_sqrt(9));
+    assert_eq!(4, ceil_sqrt(10));
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn egcd_prop(a in -1_000_000_000_i64..1_000_000_000, b in -1_000_000_000_i64..1_000_000_000) {
+        let (g, x, y) = egcd(a, b).unwrap();
+
+        prop_assert!(g >= 0);
+        prop_assert_eq!(g, a * x + b * y);
+
+        if g != 0 {
+            prop_assert!(a % g == 0 && b % g == 0);
+        }
+    }
+
+    #[test]
+    fn crt_prop(equations in prop::collection::vec((any::<i16>(), 1..100_i64), 0..5)) {
+        let equations: Vec<(i64, i64)> = equations.into_iter().map(|(r, m)| (r as i64, m)).collect();
+
+        // Any answer must satisfy every equation
+        if let Ok((x, lcm)) = crt(&equations) {
+            prop_assert!(x >= 0 && x < lcm);
+
+            for &(r, m) in &equations {
+                prop_assert_eq!(r.rem_euclid(m), x % m);
+            }
+        }
+    }
+}
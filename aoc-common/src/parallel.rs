@@ -0,0 +1,180 @@
+use std::any::Any;
+use std::error::Error;
+use std::io::Read;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use crate::diag;
+use crate::input::Input;
+use crate::solution::Day;
+
+/// How a part ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Answer(String),
+    /// The input couldn't be read or parsed, or the solution returned an error
+    Error(String),
+    /// The solution panicked with this message
+    Panic(String)
+}
+
+/// A day's input file name and contents, or why it couldn't be read
+type InputData = Result<(String, Vec<u8>), String>;
+
+/// The result of running one part of a day on its own
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartRun {
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    /// Time taken to parse the input and solve the part
+    pub time: Duration,
+    /// Debug output and warnings from the parse and solve
+    pub output: String
+}
+
+/// Runs each (day, part) on a pool of threads (None for one per CPU), parsing
+/// the input separately for each part. Inputs are read in to memory first and
+/// each part's output is captured, so parts don't interleave. A part which
+/// fails or panics doesn't stop the others. Results are in the order given
+pub fn run(parts: &[(&Day, u8)], input: Option<&str>, threads: Option<usize>) -> Result<Vec<PartRun>, Box<dyn Error>> {
+    let mut days: Vec<u8> = parts.iter().map(|(d, _)| d.day).collect();
+    days.sort_unstable();
+    days.dedup();
+
+    let inputs: Vec<(u8, InputData)> = days.into_iter().map(|day| (day, read_input(day, input))).collect();
+
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(threads.unwrap_or(0)).build()?;
+
+    // Panic messages go to the panicking part's output rather than stderr
+    let default_hook = Arc::new(panic::take_hook());
+    let hook = Arc::clone(&default_hook);
+
+    panic::set_hook(Box::new(move |info| {
+        if diag::capturing() {
+            diag::print(format_args!("{}\n", info));
+        } else {
+            hook(info);
+        }
+    }));
+
+    let runs = pool.install(|| {
+        parts.par_iter().map(|&(d, part)| {
+            let data = &inputs.iter().find(|(day, _)| *day == d.day).unwrap().1;
+
+            run_part(d, part, data)
+        }).collect()
+    });
+
+    drop(panic::take_hook());
+
+    if let Ok(default_hook) = Arc::try_unwrap(default_hook) {
+        panic::set_hook(default_hook);
+    }
+
+    Ok(runs)
+}
+
+fn read_input(day: u8, input: Option<&str>) -> InputData {
+    let (name, mut reader) = Input::new(day, input).open().map_err(|e| e.to_string())?;
+
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).map_err(|e| format!("{}: {}", name, e))?;
+
+    Ok((name, data))
+}
+
+fn run_part(d: &Day, part: u8, data: &InputData) -> PartRun {
+    diag::capture();
+
+    let start = Instant::now();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| -> Result<String, String> {
+        let (name, data) = data.as_ref().map_err(|e| e.clone())?;
+
+        let model = (d.parse)(&mut &data[..]).map_err(|e| e.in_file(name).to_string())?;
+
+        (d.solve)(model.as_ref(), part).map_err(|e| e.to_string())
+    }));
+
+    let time = start.elapsed();
+
+    // Drop the statistics, they aren't shown
+    diag::take();
+
+    let outcome = match result {
+        Ok(Ok(answer)) => Outcome::Answer(answer),
+        Ok(Err(e)) => Outcome::Error(e),
+        Err(payload) => Outcome::Panic(panic_message(payload))
+    };
+
+    PartRun { day: d.day, part, outcome, time, output: diag::take_output() }
+}
+
+/// The message a panic was raised with
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[test]
+fn run_test() {
+    use crate::parse::{self, ParseError};
+    use crate::solution::Solution;
+
+    /// Adds up the numbers in the input, part 2 panics
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+
+        type Model = u32;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &mut dyn std::io::BufRead) -> Result<u32, ParseError> {
+            let mut sum = 0;
+
+            for line_result in parse::lines(input) {
+                let line = line_result?;
+                sum += line.parse::<u32>(&line.text)?;
+            }
+
+            crate::debug!("Sum {}", sum);
+
+            Ok(sum)
+        }
+
+        fn part1(sum: &u32) -> Result<u32, Box<dyn Error>> {
+            Ok(*sum)
+        }
+
+        fn part2(_: &u32) -> Result<u32, Box<dyn Error>> {
+            panic!("no part 2")
+        }
+    }
+
+    let day = Day::of::<Sum>();
+
+    let runs = run(&[(&day, 1), (&day, 2)], Some("../example01.txt"), Some(2)).unwrap();
+
+    assert_eq!((1, Outcome::Answer("5496".to_string())), (runs[0].part, runs[0].outcome.clone()));
+    assert_eq!("Sum 5496\n", runs[0].output);
+    assert_eq!(Outcome::Panic("no part 2".to_string()), runs[1].outcome);
+    assert!(runs[1].output.starts_with("Sum 5496\n"), "Panic message follows the output");
+    assert!(runs[1].output.contains("no part 2"));
+
+    let runs = run(&[(&day, 1)], Some("../example02.txt"), None).unwrap();
+    assert!(matches!(&runs[0].outcome, Outcome::Error(e) if e.starts_with("../example02.txt:1:")), "{:?}", runs[0].outcome);
+
+    let runs = run(&[(&day, 1)], Some("no-such-input.txt"), None).unwrap();
+    assert!(matches!(runs[0].outcome, Outcome::Error(_)));
+}
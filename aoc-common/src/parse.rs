@@ -0,0 +1,275 @@
+use std::error;
+use std::fmt;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+use crate::json::Json;
+
+/// An error found parsing puzzle input, with the location of the offending token
+#[derive(Debug)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub kind: ErrorKind,
+    /// Later lines which also failed to parse
+    pub also_invalid: Vec<usize>
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    /// Failed to read the line
+    Io(io::Error),
+    /// Token is not a valid number
+    InvalidNumber(String),
+    /// Token is not what was expected here
+    Unexpected { expected: String },
+    /// Line ended before an expected token
+    Missing { expected: String },
+    /// Input ended before an expected line
+    EndOfInput { expected: String },
+    /// Token is well formed but not valid
+    Invalid(String)
+}
+
+impl ParseError {
+    /// Records the name of the input the error was found in
+    pub fn in_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    /// Records later lines which also failed to parse
+    pub fn also_invalid(mut self, lines: Vec<usize>) -> ParseError {
+        self.also_invalid = lines;
+        self
+    }
+
+    /// The error as a JSON object, for machine-readable output
+    pub fn to_json(&self) -> Json {
+        let (kind, detail) = match &self.kind {
+            ErrorKind::Io(e) => ("io", e.to_string()),
+            ErrorKind::InvalidNumber(e) => ("invalid_number", e.clone()),
+            ErrorKind::Unexpected { expected } => ("unexpected", expected.clone()),
+            ErrorKind::Missing { expected } => ("missing", expected.clone()),
+            ErrorKind::EndOfInput { expected } => ("end_of_input", expected.clone()),
+            ErrorKind::Invalid(reason) => ("invalid", reason.clone())
+        };
+
+        Json::object(vec![
+            ("file", self.file.as_deref().map_or(Json::Null, Json::str)),
+            ("line", Json::Int(self.line as i128)),
+            ("column", Json::Int(self.column as i128)),
+            ("token", Json::str(&self.token)),
+            ("kind", Json::str(kind)),
+            ("detail", Json::Str(detail)),
+            ("also_invalid", Json::Array(self.also_invalid.iter().map(|&l| Json::Int(l as i128)).collect())),
+            ("message", Json::Str(self.to_string()))
+        ])
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: ", file, self.line, self.column)?,
+            None => write!(f, "line {} column {}: ", self.line, self.column)?
+        }
+
+        match &self.kind {
+            ErrorKind::Io(e) => write!(f, "read failed: {}", e),
+            ErrorKind::InvalidNumber(e) => write!(f, "invalid number '{}': {}", self.token, e),
+            ErrorKind::Unexpected { expected } => write!(f, "unexpected '{}', expecting {}", self.token, expected),
+            ErrorKind::Missing { expected } => write!(f, "missing {}", expected),
+            ErrorKind::EndOfInput { expected } => write!(f, "input ended, expecting {}", expected),
+            ErrorKind::Invalid(reason) => write!(f, "invalid '{}': {}", self.token, reason)
+        }?;
+
+        match self.also_invalid.len() {
+            0 => Ok(()),
+            1 => write!(f, " (line {} is also invalid)", self.also_invalid[0]),
+            _ => {
+                let lines: Vec<String> = self.also_invalid.iter().map(|l| l.to_string()).collect();
+                write!(f, " (lines {} are also invalid)", lines.join(", "))
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ErrorKind::Io(e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+/// A numbered line of puzzle input
+#[derive(Debug)]
+pub struct Line {
+    pub number: usize,
+    pub text: String
+}
+
+impl Line {
+    pub fn new(number: usize, text: &str) -> Line {
+        Line {
+            number,
+            text: text.to_string()
+        }
+    }
+
+    /// 1-based character column of a token sliced from this line
+    pub fn column_of(&self, token: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let pos = token.as_ptr() as usize;
+
+        if pos >= start && pos <= start + self.text.len() {
+            self.text[..pos - start].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    /// Builds an error for a token sliced from this line
+    pub fn error(&self, token: &str, kind: ErrorKind) -> ParseError {
+        ParseError {
+            file: None,
+            line: self.number,
+            column: self.column_of(token),
+            token: token.to_string(),
+            kind,
+            also_invalid: Vec::new()
+        }
+    }
+
+    pub fn unexpected(&self, token: &str, expected: &str) -> ParseError {
+        self.error(token, ErrorKind::Unexpected { expected: expected.to_string() })
+    }
+
+    pub fn invalid(&self, token: &str, reason: &str) -> ParseError {
+        self.error(token, ErrorKind::Invalid(reason.to_string()))
+    }
+
+    /// Builds an error for a token missing from the end of this line
+    pub fn missing(&self, expected: &str) -> ParseError {
+        self.error(&self.text[self.text.len()..], ErrorKind::Missing { expected: expected.to_string() })
+    }
+
+    /// Parses a token sliced from this line
+    pub fn parse<T>(&self, token: &str) -> Result<T, ParseError>
+    where T: FromStr, T::Err: fmt::Display {
+        token.parse::<T>().map_err(|e| self.error(token, ErrorKind::InvalidNumber(e.to_string())))
+    }
+
+    /// Takes the next token from an iterator over slices of this line
+    pub fn next<'a, I>(&self, iter: &mut I, expected: &str) -> Result<&'a str, ParseError>
+    where I: Iterator<Item = &'a str> {
+        iter.next().ok_or_else(|| self.missing(expected))
+    }
+}
+
+/// Iterator over numbered lines of puzzle input
+pub struct Lines<'a> {
+    input: io::Lines<&'a mut dyn BufRead>,
+    number: usize
+}
+
+/// Reads puzzle input line by line
+pub fn lines(input: &mut dyn BufRead) -> Lines<'_> {
+    Lines {
+        input: input.lines(),
+        number: 0
+    }
+}
+
+impl Lines<'_> {
+    /// Builds an error for input ending before an expected line
+    pub fn end(&self, expected: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line: self.number + 1,
+            column: 1,
+            token: String::new(),
+            kind: ErrorKind::EndOfInput { expected: expected.to_string() },
+            also_invalid: Vec::new()
+        }
+    }
+
+    /// Reads the next line, failing if input has ended
+    pub fn expect(&mut self, expected: &str) -> Result<Line, ParseError> {
+        match self.next() {
+            Some(line) => line,
+            None => Err(self.end(expected))
+        }
+    }
+}
+
+impl Iterator for Lines<'_> {
+    type Item = Result<Line, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.input.next()?;
+
+        self.number += 1;
+
+        Some(match result {
+            Ok(text) => Ok(Line { number: self.number, text }),
+            Err(e) => Err(ParseError {
+                file: None,
+                line: self.number,
+                column: 1,
+                token: String::new(),
+                kind: ErrorKind::Io(e),
+                also_invalid: Vec::new()
+            })
+        })
+    }
+}
+
+#[test]
+fn line_error_test() {
+    let line = Line::new(3, "mem[8] = 1x1");
+
+    let mut split = line.text.split(" = ");
+    let _addr = split.next().unwrap();
+    let val = split.next().unwrap();
+
+    let err = line.parse::<u64>(val).unwrap_err();
+    assert_eq!((3, 10, "1x1"), (err.line, err.column, &err.token[..]));
+    assert_eq!("input14.txt:3:10: invalid number '1x1': invalid digit found in string", err.in_file("input14.txt").to_string());
+
+    let err = line.missing("value");
+    assert_eq!("line 3 column 13: missing value", err.to_string());
+
+    let err = line.unexpected(&line.text[0..3], "'mask'");
+    assert_eq!("line 3 column 1: unexpected 'mem', expecting 'mask'", err.to_string());
+}
+
+#[test]
+fn json_test() {
+    let line = Line::new(3, "mem[8] = 1x1");
+    let err = line.parse::<u64>(&line.text[9..]).unwrap_err().in_file("input14.txt");
+
+    assert_eq!("{\"file\":\"input14.txt\",\"line\":3,\"column\":10,\"token\":\"1x1\",\"kind\":\"invalid_number\",\
+        \"detail\":\"invalid digit found in string\",\"also_invalid\":[],\"message\":\"input14.txt:3:10: invalid number '1x1': invalid digit found in string\"}",
+        err.to_json().to_string());
+
+    let err = line.missing("value").also_invalid(vec![5, 8]);
+
+    assert_eq!("{\"file\":null,\"line\":3,\"column\":13,\"token\":\"\",\"kind\":\"missing\",\"detail\":\"value\",\"also_invalid\":[5,8],\
+        \"message\":\"line 3 column 13: missing value (lines 5, 8 are also invalid)\"}",
+        err.to_json().to_string());
+}
+
+#[test]
+fn lines_test() {
+    let mut input = "a\nb\n".as_bytes();
+    let mut lines = lines(&mut input);
+
+    assert_eq!("a", lines.expect("first").unwrap().text);
+    assert_eq!(2, lines.next().unwrap().unwrap().number);
+    assert_eq!("line 3 column 1: input ended, expecting third", lines.expect("third").unwrap_err().to_string());
+}
@@ -0,0 +1,171 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt::Display;
+use std::io::BufRead;
+
+use crate::input::Input;
+use crate::parse::ParseError;
+
+/// A day's puzzle solution. The input is parsed once into a model which both parts then solve
+pub trait Solution {
+    /// Day number
+    const DAY: u8;
+
+    /// Number of puzzle parts. Day 25 only has one
+    const PARTS: u8 = 2;
+
+    /// Parsed puzzle input
+    type Model: 'static;
+
+    /// Part 1 answer
+    type Answer1: Display;
+
+    /// Part 2 answer
+    type Answer2: Display;
+
+    /// Parses the puzzle input
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError>;
+
+    /// Solves part 1
+    fn part1(model: &Self::Model) -> Result<Self::Answer1, Box<dyn Error>>;
+
+    /// Solves part 2
+    fn part2(model: &Self::Model) -> Result<Self::Answer2, Box<dyn Error>>;
+}
+
+/// The answer to one part of a day's puzzle
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub part: u8,
+    pub value: String
+}
+
+/// Parses the input into a model of the day's type
+pub type ParseFn = fn(&mut dyn BufRead) -> Result<Box<dyn Any>, ParseError>;
+
+/// Solves one part from a model produced by the day's ParseFn
+pub type SolveFn = fn(&dyn Any, u8) -> Result<String, Box<dyn Error>>;
+
+/// A day's solution with its model and answer types erased, for selecting and running by number
+pub struct Day {
+    pub day: u8,
+    pub parts: u8,
+    pub parse: ParseFn,
+    pub solve: SolveFn
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            parts: S::PARTS,
+            parse: parse::<S>,
+            solve: solve::<S>
+        }
+    }
+
+    /// Parses the input and solves each requested part
+    pub fn run(&self, input: &mut dyn BufRead, parts: &[u8]) -> Result<Vec<Answer>, Box<dyn Error>> {
+        let model = (self.parse)(input)?;
+
+        self.solve_parts(model.as_ref(), parts)
+    }
+
+    /// Opens the day's input, then parses it and solves each requested part.
+    /// Parse errors are reported against the input file
+    pub fn run_input(&self, input: &Input, parts: &[u8]) -> Result<Vec<Answer>, Box<dyn Error>> {
+        let (_, model) = self.parse_input(input)?;
+
+        self.solve_parts(model.as_ref(), parts)
+    }
+
+    /// Opens and parses the day's input, returning the input's name with the model.
+    /// Parse errors are reported against the input file
+    pub fn parse_input(&self, input: &Input) -> Result<(String, Box<dyn Any>), Box<dyn Error>> {
+        let (name, mut reader) = input.open()?;
+
+        let model = (self.parse)(&mut *reader).map_err(|e| e.in_file(&name))?;
+
+        Ok((name, model))
+    }
+
+    fn solve_parts(&self, model: &dyn Any, parts: &[u8]) -> Result<Vec<Answer>, Box<dyn Error>> {
+        let mut answers = Vec::new();
+
+        for &part in parts {
+            answers.push(Answer { part, value: (self.solve)(model, part)? });
+        }
+
+        Ok(answers)
+    }
+}
+
+fn parse<S: Solution>(input: &mut dyn BufRead) -> Result<Box<dyn Any>, ParseError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn solve<S: Solution>(model: &dyn Any, part: u8) -> Result<String, Box<dyn Error>> {
+    let model = match model.downcast_ref::<S::Model>() {
+        Some(model) => model,
+        None => Err(format!("Day {} given a model of the wrong type", S::DAY))?
+    };
+
+    match part {
+        1 => Ok(S::part1(model)?.to_string()),
+        2 if S::PARTS >= 2 => Ok(S::part2(model)?.to_string()),
+        _ => Err(format!("Day {} has no part {}", S::DAY, part))?
+    }
+}
+
+#[cfg(test)]
+struct Summer;
+
+#[cfg(test)]
+impl Solution for Summer {
+    const DAY: u8 = 99;
+
+    type Model = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = String;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        let mut numbers = Vec::new();
+
+        for line in crate::parse::lines(input) {
+            let line = line?;
+
+            numbers.push(line.parse(&line.text)?);
+        }
+
+        Ok(numbers)
+    }
+
+    fn part1(model: &Self::Model) -> Result<Self::Answer1, Box<dyn Error>> {
+        Ok(model.iter().sum())
+    }
+
+    fn part2(model: &Self::Model) -> Result<Self::Answer2, Box<dyn Error>> {
+        Ok(format!("{} numbers", model.len()))
+    }
+}
+
+#[test]
+fn run_test() {
+    let day = Day::of::<Summer>();
+
+    let answers = day.run(&mut "1\n2\n3\n".as_bytes(), &[1, 2]).unwrap();
+
+    assert_eq!(vec![
+        Answer { part: 1, value: "6".to_string() },
+        Answer { part: 2, value: "3 numbers".to_string() }
+    ], answers);
+
+    match day.run(&mut "1\nx\n".as_bytes(), &[1]) {
+        Err(e) => assert_eq!("line 2 column 1: invalid number 'x': invalid digit found in string", e.to_string()),
+        Ok(_) => panic!("Parse error should be returned")
+    }
+
+    assert!(day.run(&mut "1\n".as_bytes(), &[3]).is_err(), "Part 3 should not exist");
+
+    assert!((day.solve)(&"wrong", 1).is_err(), "Wrong model type should fail");
+}
@@ -0,0 +1,190 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+use crate::input::{Input, InputSource};
+use crate::parallel::panic_message;
+use crate::solution::Day;
+
+/// Modification time and length of a file, None if it can't be read
+type Stamp = Option<(Option<SystemTime>, u64)>;
+
+/// How a part's answer compares with the last run
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    /// There was no answer before
+    New(String),
+    Same(String),
+    Changed { old: String, new: String },
+    /// Solving failed, the last answer is kept for comparison
+    Error(String)
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::New(answer) => write!(f, "{}", answer),
+            Change::Same(answer) => write!(f, "{} (unchanged)", answer),
+            Change::Changed { old, new } => write!(f, "{} (was {})", new, old),
+            Change::Error(e) => write!(f, "ERROR, {}", e)
+        }
+    }
+}
+
+/// An input file being watched for changes, with the answers from its last run
+pub struct Watched {
+    pub path: PathBuf,
+    parts: Vec<u8>,
+    stamp: Option<Stamp>,
+    answers: Vec<Option<String>>
+}
+
+impl Watched {
+    pub fn new(path: PathBuf, parts: Vec<u8>) -> Watched {
+        Watched {
+            answers: vec![None; parts.len()],
+            path,
+            parts,
+            stamp: None
+        }
+    }
+
+    /// Whether the file has changed since the last call. The first call
+    /// always reports a change
+    pub fn changed(&mut self) -> bool {
+        let stamp = fs::metadata(&self.path).ok().map(|m| (m.modified().ok(), m.len()));
+
+        if self.stamp.as_ref() == Some(&stamp) {
+            return false
+        }
+
+        self.stamp = Some(stamp);
+
+        true
+    }
+
+    /// Parses the file and solves each part, comparing the answers with the
+    /// last run. Parse errors are returned, keeping the last answers. Panics
+    /// are reported as errors, as edited inputs often break a solution's
+    /// assumptions
+    pub fn run(&mut self, day: &Day) -> Result<Vec<(u8, Change)>, Box<dyn Error>> {
+        let input = Input {
+            day: day.day,
+            source: InputSource::File(self.path.clone())
+        };
+
+        let (_, model) = panic::catch_unwind(AssertUnwindSafe(|| day.parse_input(&input)))
+            .map_err(|payload| format!("parse panicked: {}", panic_message(payload)))??;
+
+        let mut changes = Vec::new();
+
+        for (&part, last) in self.parts.iter().zip(self.answers.iter_mut()) {
+            let answer = panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(model.as_ref(), part)))
+                .unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(payload)).into()));
+
+            let change = match (answer, last.as_ref()) {
+                (Err(e), _) => Change::Error(e.to_string()),
+                (Ok(new), None) => Change::New(new),
+                (Ok(new), Some(old)) if new == *old => Change::Same(new),
+                (Ok(new), Some(old)) => Change::Changed { old: old.clone(), new }
+            };
+
+            match &change {
+                Change::New(answer) | Change::Same(answer) | Change::Changed { new: answer, .. } => *last = Some(answer.clone()),
+                Change::Error(_) => ()
+            }
+
+            changes.push((part, change));
+        }
+
+        Ok(changes)
+    }
+}
+
+#[test]
+fn watch_test() {
+    use crate::parse::{self, ParseError};
+    use crate::solution::Solution;
+
+    /// Adds up the numbers in the input, part 2 fails on odd sums and panics on 0
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+
+        type Model = u32;
+        type Answer1 = u32;
+        type Answer2 = u32;
+
+        fn parse(input: &mut dyn std::io::BufRead) -> Result<u32, ParseError> {
+            let mut sum = 0;
+
+            for line_result in parse::lines(input) {
+                let line = line_result?;
+                sum += line.parse::<u32>(&line.text)?;
+            }
+
+            Ok(sum)
+        }
+
+        fn part1(sum: &u32) -> Result<u32, Box<dyn Error>> {
+            Ok(*sum)
+        }
+
+        fn part2(sum: &u32) -> Result<u32, Box<dyn Error>> {
+            if sum % 2 == 1 {
+                Err("odd sum")?
+            }
+
+            assert!(*sum != 0, "zero sum");
+
+            Ok(sum / 2)
+        }
+    }
+
+    let day = Day::of::<Sum>();
+
+    let path = std::env::temp_dir().join(format!("aoc-watch-test-{}.txt", std::process::id()));
+    let mut watched = Watched::new(path.clone(), vec![1, 2]);
+
+    // Lengths differ each time so the change is seen whatever the timestamp resolution
+    fs::write(&path, "4\n").unwrap();
+
+    assert!(watched.changed());
+    assert!(!watched.changed());
+    assert_eq!(vec![(1, Change::New("4".to_string())), (2, Change::New("2".to_string()))], watched.run(&day).unwrap());
+
+    fs::write(&path, "1\n4\n").unwrap();
+
+    assert!(watched.changed());
+    assert_eq!(vec![
+        (1, Change::Changed { old: "4".to_string(), new: "5".to_string() }),
+        (2, Change::Error("odd sum".to_string()))
+    ], watched.run(&day).unwrap());
+
+    fs::write(&path, "x\n").unwrap();
+
+    assert!(watched.changed());
+    assert!(watched.run(&day).is_err());
+
+    fs::write(&path, "1\n1\n2\n").unwrap();
+
+    assert!(watched.changed());
+    assert_eq!(vec![(1, Change::Changed { old: "5".to_string(), new: "4".to_string() }), (2, Change::Same("2".to_string()))],
+        watched.run(&day).unwrap());
+
+    fs::write(&path, "0\n").unwrap();
+
+    assert!(watched.changed());
+    assert_eq!(vec![(1, Change::Changed { old: "4".to_string(), new: "0".to_string() }), (2, Change::Error("panicked: zero sum".to_string()))],
+        watched.run(&day).unwrap());
+
+    fs::remove_file(&path).unwrap();
+
+    assert!(watched.changed());
+    assert!(watched.run(&day).is_err());
+    assert_eq!("7 (was 5)", Change::Changed { old: "5".to_string(), new: "7".to_string() }.to_string());
+}
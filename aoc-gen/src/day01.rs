@@ -0,0 +1,67 @@
+//! Expense report with exactly one pair and one triple summing to 2020
+//!
+//! Settings: numbers (200)
+
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{lines, GenRng, Settings, MAX_ATTEMPTS};
+
+const SUM: u32 = 2020;
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("numbers", 200, 5..=900)?;
+
+    for _ in 0..MAX_ATTEMPTS {
+        let a = rng.gen_range(100..SUM / 2);
+        let x = rng.gen_range(100..SUM / 3);
+        let y = rng.gen_range(100..SUM / 3);
+
+        let mut numbers = vec![a, SUM - a, x, y, SUM - x - y];
+
+        // Pad with numbers over half the sum which don't pair with the small ones
+        let avoid: HashSet<u32> = numbers.iter().map(|&n| SUM - n).chain(numbers.iter().copied()).collect();
+
+        let mut fill: Vec<u32> = (SUM / 2 + 1..SUM).filter(|n| !avoid.contains(n)).collect();
+        fill.shuffle(rng);
+
+        numbers.extend(fill.into_iter().take(count - 5));
+
+        if sums(&numbers) == (1, 1) {
+            numbers.shuffle(rng);
+
+            return Ok(lines(&numbers))
+        }
+    }
+
+    Err("couldn't find numbers with a single pair and triple".to_string())
+}
+
+/// Counts the distinct pairs and triples summing to SUM
+fn sums(numbers: &[u32]) -> (usize, usize) {
+    let set: HashSet<u32> = numbers.iter().copied().collect();
+
+    if set.len() != numbers.len() {
+        return (0, 0)
+    }
+
+    let pairs = numbers.iter().filter(|&&a| a < SUM - a && set.contains(&(SUM - a))).count();
+
+    let mut triples = 0;
+
+    for &a in numbers {
+        for &b in numbers {
+            if a < b && a + b < SUM {
+                let c = SUM - a - b;
+
+                if b < c && set.contains(&c) {
+                    triples += 1;
+                }
+            }
+        }
+    }
+
+    (pairs, triples)
+}
@@ -0,0 +1,33 @@
+//! Password database, roughly half the passwords valid under each policy
+//!
+//! Settings: passwords (1000)
+
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("passwords", 1000, 1..=1_000_000)?;
+
+    let mut input = String::new();
+
+    for _ in 0..count {
+        let len = rng.gen_range(4..=20);
+        let n1 = rng.gen_range(1..len);
+        let n2 = rng.gen_range(n1 + 1..=len);
+
+        let pchar = random_letter(rng);
+
+        let password: String = (0..len).map(|_| {
+            if rng.gen_bool(0.35) { pchar } else { random_letter(rng) }
+        }).collect();
+
+        input += &format!("{}-{} {}: {}\n", n1, n2, pchar, password);
+    }
+
+    Ok(input)
+}
+
+fn random_letter(rng: &mut GenRng) -> char {
+    rng.gen_range(b'a'..=b'z') as char
+}
@@ -0,0 +1,22 @@
+//! Tree map
+//!
+//! Settings: width (31), height (323), trees (0.25, the fraction of squares with trees)
+
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let width = settings.get_range("width", 31, 1..=1000)?;
+    let height = settings.get_range("height", 323, 1..=10000)?;
+    let trees = settings.get_range("trees", 0.25, 0.0..=1.0)?;
+
+    let mut input = String::new();
+
+    for _ in 0..height {
+        input.extend((0..width).map(|_| if rng.gen_bool(trees) { '#' } else { '.' }));
+        input.push('\n');
+    }
+
+    Ok(input)
+}
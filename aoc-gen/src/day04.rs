@@ -0,0 +1,102 @@
+//! Passport batch with a controllable share of passports missing a required
+//! field or holding an invalid value
+//!
+//! Settings: passports (290), missing (0.2), invalid (0.3)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+const EYE_COLOURS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("passports", 290, 1..=60000)?;
+    let missing = settings.get_range("missing", 0.2, 0.0..=1.0)?;
+    let invalid = settings.get_range("invalid", 0.3, 0.0..=1.0)?;
+
+    let mut passports = Vec::new();
+
+    for _ in 0..count {
+        let mut fields: Vec<(&str, String)> = REQUIRED.iter().map(|&name| (name, valid_value(rng, name))).collect();
+
+        if rng.gen_bool(invalid) {
+            let field = fields.choose_mut(rng).unwrap();
+            field.1 = invalid_value(rng, field.0);
+        }
+
+        if rng.gen_bool(missing) {
+            fields.remove(rng.gen_range(0..fields.len()));
+        }
+
+        // Country ID is optional
+        if rng.gen_bool(0.5) {
+            fields.push(("cid", rng.gen_range(100..=350).to_string()));
+        }
+
+        fields.shuffle(rng);
+
+        // Spread the fields over a few lines
+        let mut passport = String::new();
+
+        for (i, (name, value)) in fields.iter().enumerate() {
+            if i > 0 {
+                passport.push(if rng.gen_bool(0.25) { '\n' } else { ' ' });
+            }
+
+            passport += &format!("{}:{}", name, value);
+        }
+
+        passports.push(passport + "\n");
+    }
+
+    Ok(passports.join("\n"))
+}
+
+fn valid_value(rng: &mut GenRng, field: &str) -> String {
+    match field {
+        "byr" => rng.gen_range(1920..=2002).to_string(),
+        "iyr" => rng.gen_range(2010..=2020).to_string(),
+        "eyr" => rng.gen_range(2020..=2030).to_string(),
+        "hgt" => if rng.gen() {
+            format!("{}cm", rng.gen_range(150..=193))
+        } else {
+            format!("{}in", rng.gen_range(59..=76))
+        },
+        "hcl" => format!("#{:06x}", rng.gen_range(0..0x1000000)),
+        "ecl" => EYE_COLOURS.choose(rng).unwrap().to_string(),
+        "pid" => format!("{:09}", rng.gen_range(0..1_000_000_000)),
+        _ => unreachable!()
+    }
+}
+
+fn invalid_value(rng: &mut GenRng, field: &str) -> String {
+    let year = |rng: &mut GenRng, min: u16, max: u16| {
+        if rng.gen() { rng.gen_range(min - 20..min) } else { rng.gen_range(max + 1..=max + 20) }.to_string()
+    };
+
+    match field {
+        "byr" => year(rng, 1920, 2002),
+        "iyr" => year(rng, 2010, 2020),
+        "eyr" => year(rng, 2020, 2030),
+        "hgt" => match rng.gen_range(0..3) {
+            0 => format!("{}cm", rng.gen_range(100..150)),
+            1 => format!("{}in", rng.gen_range(77..100)),
+            _ => rng.gen_range(59..=193).to_string()
+        },
+        "hcl" => if rng.gen() {
+            format!("{:06x}", rng.gen_range(0..0x1000000))
+        } else {
+            format!("#{:06x}z", rng.gen_range(0..0x100000))
+        },
+        "ecl" => ["xry", "zzz", "blue", "#123abc"].choose(rng).unwrap().to_string(),
+        "pid" => if rng.gen() {
+            format!("{:08}", rng.gen_range(0..100_000_000))
+        } else {
+            format!("{:010}", rng.gen_range(0..10_000_000_000u64))
+        },
+        _ => unreachable!()
+    }
+}
@@ -0,0 +1,43 @@
+//! Boarding passes for a block of seats with one free seat in the middle
+//!
+//! Settings: passes (800)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{lines, GenRng, Settings};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count: u16 = settings.get_range("passes", 800, 2..=1000)?;
+
+    // Keep clear of the front and back rows
+    let first = rng.gen_range(8..1016 - count);
+
+    let mut seats: Vec<u16> = (first..=first + count).collect();
+    seats.remove(rng.gen_range(1..count) as usize);
+    seats.shuffle(rng);
+
+    let passes: Vec<String> = seats.iter().map(|&seat| encode(seat)).collect();
+
+    Ok(lines(&passes))
+}
+
+/// Seat ID to binary space partitioning
+fn encode(seat: u16) -> String {
+    (0..10).rev().map(|bit| {
+        let set = seat & (1 << bit) != 0;
+
+        match (bit >= 3, set) {
+            (true, false) => 'F',
+            (true, true) => 'B',
+            (false, false) => 'L',
+            (false, true) => 'R'
+        }
+    }).collect()
+}
+
+#[test]
+fn encode_test() {
+    assert_eq!("FBFBBFFRLR", encode(357));
+    assert_eq!("BBFFBBFRLL", encode(820));
+}
@@ -0,0 +1,49 @@
+//! Customs declaration groups, each person answering the questions their
+//! group has in common plus a few of their own
+//!
+//! Settings: groups (490)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("groups", 490, 1..=60000)?;
+
+    let questions: Vec<char> = ('a'..='z').collect();
+
+    let mut groups = Vec::new();
+
+    for _ in 0..count {
+        let shared = rng.gen_range(0..=8);
+        let common: Vec<char> = questions.choose_multiple(rng, shared).copied().collect();
+
+        let mut group = String::new();
+
+        for _ in 0..rng.gen_range(1..=5) {
+            let mut answers = common.clone();
+
+            let own = rng.gen_range(0..=10);
+
+            for &q in questions.choose_multiple(rng, own) {
+                if !answers.contains(&q) {
+                    answers.push(q);
+                }
+            }
+
+            if answers.is_empty() {
+                answers.push(*questions.choose(rng).unwrap());
+            }
+
+            answers.shuffle(rng);
+
+            group.extend(answers);
+            group.push('\n');
+        }
+
+        groups.push(group);
+    }
+
+    Ok(groups.join("\n"))
+}
@@ -0,0 +1,96 @@
+//! Bag rules forming a layered graph without cycles. Every bag in a layer
+//! holds at least one bag from the next, so the longest chain of bags is the
+//! depth. Shiny gold sits in the middle layer
+//!
+//! Settings: bags (594), depth (8)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+const ADJECTIVES: [&str; 33] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored", "muted",
+    "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dusky", "shabby", "vague", "frosted",
+    "dazzling", "glossy", "grimy", "matte", "misty", "rusty", "smoky", "speckled", "spotted", "tinted", "worn"
+];
+
+const COLOURS: [&str; 33] = [
+    "aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral", "crimson", "cyan", "fuchsia",
+    "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive", "orange", "plum",
+    "purple", "red", "salmon", "silver", "tan", "teal", "tomato", "turquoise", "violet", "white", "yellow"
+];
+
+const GOLD: &str = "shiny gold";
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("bags", 594, 3..=ADJECTIVES.len() * COLOURS.len())?;
+    let depth = settings.get_range("depth", 8, 2..=12)?;
+
+    if count <= depth {
+        Err(format!("{} bags can't be {} deep", count, depth))?
+    }
+
+    let mut names: Vec<String> = ADJECTIVES.iter()
+        .flat_map(|a| COLOURS.iter().map(move |c| format!("{} {}", a, c)))
+        .filter(|name| name != GOLD)
+        .collect();
+
+    names.shuffle(rng);
+    names.truncate(count - 1);
+
+    // Every layer gets at least one bag, gold at the start of the middle one
+    let mut layers: Vec<Vec<String>> = vec![Vec::new(); depth + 1];
+    let gold_layer = depth / 2;
+
+    layers[gold_layer].push(GOLD.to_string());
+
+    for (i, name) in names.into_iter().enumerate() {
+        let layer = if i <= depth { i } else { rng.gen_range(0..=depth) };
+
+        layers[layer].push(name);
+    }
+
+    let mut rules = Vec::new();
+
+    for layer in 0..=depth {
+        for (i, bag) in layers[layer].iter().enumerate() {
+            let mut contents: Vec<&String> = Vec::new();
+
+            if layer < depth {
+                // Something from the next layer, and gold's first container holds gold
+                if layer + 1 == gold_layer && i == 0 {
+                    contents.push(&layers[gold_layer][0]);
+                } else {
+                    contents.push(layers[layer + 1].choose(rng).unwrap());
+                }
+
+                for _ in 0..rng.gen_range(0..=3) {
+                    let deeper = &layers[rng.gen_range(layer + 1..=depth)];
+                    let inner = deeper.choose(rng).unwrap();
+
+                    if !contents.contains(&inner) {
+                        contents.push(inner);
+                    }
+                }
+            }
+
+            let contents: Vec<String> = contents.iter().map(|inner| {
+                match rng.gen_range(1..=5) {
+                    1 => format!("1 {} bag", inner),
+                    n => format!("{} {} bags", n, inner)
+                }
+            }).collect();
+
+            if contents.is_empty() {
+                rules.push(format!("{} bags contain no other bags.\n", bag));
+            } else {
+                rules.push(format!("{} bags contain {}.\n", bag, contents.join(", ")));
+            }
+        }
+    }
+
+    rules.shuffle(rng);
+
+    Ok(rules.concat())
+}
@@ -0,0 +1,128 @@
+//! Boot code which loops, with exactly one nop or jmp that can be flipped to
+//! let it finish
+//!
+//! Settings: instructions (650)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{GenRng, Settings, MAX_ATTEMPTS};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Op {
+    Acc,
+    Jmp,
+    Nop
+}
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let len = settings.get_range("instructions", 650, 10..=100_000)?;
+
+    for _ in 0..MAX_ATTEMPTS {
+        let mut program: Vec<(Op, i64)> = (0..len).map(|pc| {
+            match rng.gen_range(0..10) {
+                0..=3 => (Op::Acc, rng.gen_range(-50..=50)),
+                4..=7 => (Op::Jmp, jump(rng, pc, len)),
+                _ => (Op::Nop, jump(rng, pc, len))
+            }
+        }).collect();
+
+        let next = |program: &[(Op, i64)], pc: usize| match program[pc] {
+            (Op::Jmp, arg) => (pc as i64 + arg) as usize,
+            _ => pc + 1
+        };
+
+        // Run until an instruction repeats, giving up on programs which finish
+        let mut visited = vec![false; len];
+        let mut pc = 0;
+
+        while pc < len && !visited[pc] {
+            visited[pc] = true;
+            pc = next(&program, pc);
+        }
+
+        if pc == len {
+            continue
+        }
+
+        // Instructions from which the program finishes
+        let mut finishes: Vec<Option<bool>> = vec![None; len + 1];
+        finishes[len] = Some(true);
+
+        let mut on_path = vec![false; len];
+
+        for start in 0..len {
+            let mut path = Vec::new();
+            let mut pc = start;
+
+            let result = loop {
+                if let Some(result) = finishes[pc] {
+                    break result
+                }
+
+                if on_path[pc] {
+                    break false
+                }
+
+                on_path[pc] = true;
+                path.push(pc);
+                pc = next(&program, pc);
+            };
+
+            for pc in path {
+                on_path[pc] = false;
+                finishes[pc] = Some(result);
+            }
+        }
+
+        let finishes: Vec<bool> = finishes.into_iter().map(|f| f.unwrap()).collect();
+
+        // A jmp on the path that finishes as a nop can't be undone, try again
+        if (0..len).any(|pc| visited[pc] && program[pc].0 == Op::Jmp && finishes[pc + 1]) {
+            continue
+        }
+
+        let nops: Vec<usize> = (0..len).filter(|&pc| visited[pc] && program[pc].0 == Op::Nop).collect();
+
+        let fix = match nops.choose(rng) {
+            Some(&fix) => fix,
+            None => continue
+        };
+
+        // Point the chosen nop somewhere the program finishes from, and the
+        // other nops on the path somewhere it doesn't
+        let good: Vec<usize> = (0..=len).filter(|&pc| finishes[pc]).collect();
+        let bad: Vec<usize> = (0..len).filter(|&pc| !finishes[pc]).collect();
+
+        for &pc in &nops {
+            let target = if pc == fix { good.choose(rng) } else { bad.choose(rng) };
+
+            program[pc].1 = *target.unwrap() as i64 - pc as i64;
+        }
+
+        let lines: Vec<String> = program.iter().map(|(op, arg)| {
+            let op = match op {
+                Op::Acc => "acc",
+                Op::Jmp => "jmp",
+                Op::Nop => "nop"
+            };
+
+            format!("{} {:+}\n", op, arg)
+        }).collect();
+
+        return Ok(lines.concat())
+    }
+
+    Err("couldn't build a program with a single fix".to_string())
+}
+
+/// Random jump offset staying within the program, or to just past its end
+fn jump(rng: &mut GenRng, pc: usize, len: usize) -> i64 {
+    let target = if rng.gen_bool(0.7) {
+        rng.gen_range(pc + 1..=(pc + 20).min(len))
+    } else {
+        rng.gen_range(pc.saturating_sub(200)..=pc)
+    };
+
+    target as i64 - pc as i64
+}
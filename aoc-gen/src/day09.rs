@@ -0,0 +1,64 @@
+//! XMAS data where each number is the sum of two of the previous 25, except
+//! for one which is the sum of a contiguous range of earlier numbers
+//!
+//! Settings: numbers (1000)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{lines, GenRng, Settings, MAX_ATTEMPTS};
+
+const PREAMBLE: usize = 25;
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("numbers", 1000, PREAMBLE + 5..=2000)?;
+
+    let mut numbers: Vec<u64> = (1..=PREAMBLE as u64 * 2).collect();
+    numbers.shuffle(rng);
+    numbers.truncate(PREAMBLE);
+
+    // Not the last number, which isn't checked
+    let invalid = rng.gen_range(count / 2..count - 1);
+
+    for i in PREAMBLE..count {
+        let window = &numbers[i - PREAMBLE..i];
+
+        let number = if i == invalid {
+            contiguous_sum(rng, &numbers)?
+        } else {
+            // Sums of the smaller numbers keep the growth down
+            let mut order: Vec<usize> = (0..PREAMBLE).collect();
+            order.sort_by_key(|&j| window[j]);
+
+            let pair: Vec<&usize> = order[..6].choose_multiple(rng, 2).collect();
+
+            window[*pair[0]] + window[*pair[1]]
+        };
+
+        numbers.push(number);
+    }
+
+    Ok(lines(&numbers))
+}
+
+/// Sum of a random range of two or more numbers which isn't the sum of two
+/// of the last PREAMBLE numbers
+fn contiguous_sum(rng: &mut GenRng, numbers: &[u64]) -> Result<u64, String> {
+    let window = &numbers[numbers.len() - PREAMBLE..];
+
+    for _ in 0..MAX_ATTEMPTS {
+        let len = rng.gen_range(2..=17);
+        let start = rng.gen_range(0..=numbers.len() - len);
+
+        let sum = numbers[start..start + len].iter().sum();
+
+        let is_pair = window.iter().enumerate()
+            .any(|(j, &a)| window[..j].iter().any(|&b| a + b == sum));
+
+        if !is_pair {
+            return Ok(sum)
+        }
+    }
+
+    Err("couldn't find an invalid number".to_string())
+}
@@ -0,0 +1,39 @@
+//! Joltage adapters differing by 1 or 3, with at most four 1 jolt steps in a row
+//!
+//! Settings: adapters (95)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{lines, GenRng, Settings, MAX_ATTEMPTS};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("adapters", 95, 1..=100)?;
+
+    for _ in 0..MAX_ATTEMPTS {
+        let mut adapters: Vec<u32> = Vec::new();
+        let mut jolts = 0;
+        let mut ones = 0;
+
+        while adapters.len() < count {
+            if ones < 4 && rng.gen_bool(0.7) {
+                jolts += 1;
+                ones += 1;
+            } else {
+                jolts += 3;
+                ones = 0;
+            }
+
+            adapters.push(jolts);
+        }
+
+        // Adapters are rated up to 255 jolts
+        if jolts <= 255 {
+            adapters.shuffle(rng);
+
+            return Ok(lines(&adapters))
+        }
+    }
+
+    Err("couldn't fit the adapters under 255 jolts".to_string())
+}
@@ -0,0 +1,107 @@
+//! Seat layout. In random layouts there are often patches of seats which
+//! all fill and empty together forever. A seat in each patch gets some of
+//! the seats around it turned into floor, so it stays occupied and the patch
+//! settles around it like the edges do. This repeats until the layout
+//! settles under both sets of rules
+//!
+//! Settings: width (97), height (93), floor (0.18, the fraction of floor squares)
+
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use aoc_common::ca::{Automaton, LineOfSight, Outcome, Rect, Rule, Topology};
+use aoc_common::Grid;
+
+use crate::{GenRng, Settings, MAX_ATTEMPTS};
+
+/// Generations the solution allows for settling
+const MAX_GENERATIONS: usize = 10_000;
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let width = settings.get_range("width", 97, 1..=1000)?;
+    let height = settings.get_range("height", 93, 1..=1000)?;
+    let floor = settings.get_range("floor", 0.18, 0.0..=1.0)?;
+
+    let mut seats = Grid::from_fn(width, height, |_, _| !rng.gen_bool(floor));
+
+    for _ in 0..MAX_ATTEMPTS {
+        let mut flipping = unsettled(Rect::new(&seats, |&s| s), 4);
+
+        if flipping.is_empty() {
+            flipping = unsettled(LineOfSight::new(&seats, |&s| s), 5);
+        }
+
+        if flipping.is_empty() {
+            return Ok(seats.render(|&s| if s { 'L' } else { '.' }))
+        }
+
+        for patch in patches(flipping) {
+            let (x, y) = *patch.choose(rng).unwrap();
+
+            // Leave the seat fewer than 4 neighbours
+            let mut around: Vec<(usize, usize)> = seats.neighbours8(x, y).filter(|&pos| seats[pos]).collect();
+            around.shuffle(rng);
+
+            for &pos in around.iter().skip(3) {
+                seats[pos] = false;
+            }
+        }
+    }
+
+    Err("couldn't find a layout which settles".to_string())
+}
+
+/// Seats which keep changing, starting with every seat empty
+fn unsettled<T>(seats: T, tolerance: usize) -> HashSet<(usize, usize)>
+where T: Topology<Cell = (usize, usize)> {
+    let rule = Rule::new(&[0], &(0..tolerance).collect::<Vec<_>>());
+
+    let mut automaton = Automaton::new(seats, rule, Vec::new());
+
+    match automaton.settle(MAX_GENERATIONS) {
+        Outcome::Fixed(_) => HashSet::new(),
+        _ => {
+            let before = automaton.live().clone();
+            automaton.step();
+
+            before.symmetric_difference(automaton.live()).copied().collect()
+        }
+    }
+}
+
+/// Splits seats into groups of adjacent seats
+fn patches(mut seats: HashSet<(usize, usize)>) -> Vec<Vec<(usize, usize)>> {
+    let mut result = Vec::new();
+
+    while let Some(&start) = seats.iter().next() {
+        seats.remove(&start);
+
+        let mut patch = vec![start];
+        let mut i = 0;
+
+        while i < patch.len() {
+            let (x, y) = patch[i];
+
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let pos = ((x as isize + dx) as usize, (y as isize + dy) as usize);
+
+                    if seats.remove(&pos) {
+                        patch.push(pos);
+                    }
+                }
+            }
+
+            i += 1;
+        }
+
+        patch.sort_unstable();
+        result.push(patch);
+    }
+
+    result.sort_unstable();
+
+    result
+}
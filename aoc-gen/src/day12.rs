@@ -0,0 +1,99 @@
+//! Navigation instructions. Moves which would take the ship or waypoint too
+//! far out are replaced, keeping the answers in range
+//!
+//! Settings: actions (780)
+
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+/// Furthest the ship gets in part 1
+const SHIP_LIMIT: i64 = 10_000;
+
+/// Furthest the waypoint gets from the ship in part 2
+const WAYPOINT_LIMIT: i64 = 1000;
+
+/// Furthest the ship gets in part 2
+const WAYPOINT_SHIP_LIMIT: i64 = 1_000_000_000;
+
+#[derive(Clone, Copy)]
+struct State {
+    ship: (i64, i64),
+    bearing: i64,
+    waypoint: (i64, i64),
+    waypoint_ship: (i64, i64)
+}
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("actions", 780, 1..=100_000)?;
+
+    let mut state = State { ship: (0, 0), bearing: 90, waypoint: (10, 1), waypoint_ship: (0, 0) };
+    let mut input = String::new();
+    let mut actions = 0;
+
+    while actions < count {
+        let action = ['N', 'S', 'E', 'W', 'L', 'R', 'F', 'F'][rng.gen_range(0..8)];
+
+        let value = match action {
+            'L' | 'R' => rng.gen_range(1..=3) * 90,
+            'F' => rng.gen_range(1..=100),
+            _ => rng.gen_range(1..=5)
+        };
+
+        let next = step(state, action, value);
+
+        let within = |(x, y): (i64, i64), limit| x.abs() <= limit && y.abs() <= limit;
+
+        if within(next.ship, SHIP_LIMIT) && within(next.waypoint, WAYPOINT_LIMIT) && within(next.waypoint_ship, WAYPOINT_SHIP_LIMIT) {
+            state = next;
+            input += &format!("{}{}\n", action, value);
+            actions += 1;
+        }
+    }
+
+    Ok(input)
+}
+
+/// Takes an action for both parts
+fn step(mut state: State, action: char, value: i64) -> State {
+    let direction = |bearing| match bearing {
+        0 => (0, 1),
+        90 => (1, 0),
+        180 => (0, -1),
+        _ => (-1, 0)
+    };
+
+    let rotate = |(x, y): (i64, i64), degrees| (0..degrees / 90).fold((x, y), |(x, y), _| (y, -x));
+
+    let (dx, dy) = match action {
+        'N' => direction(0),
+        'S' => direction(180),
+        'E' => direction(90),
+        'W' => direction(270),
+        _ => (0, 0)
+    };
+
+    state.ship = (state.ship.0 + dx * value, state.ship.1 + dy * value);
+    state.waypoint = (state.waypoint.0 + dx * value, state.waypoint.1 + dy * value);
+
+    match action {
+        'L' => {
+            state.bearing = (state.bearing + 360 - value) % 360;
+            state.waypoint = rotate(state.waypoint, 360 - value);
+        }
+        'R' => {
+            state.bearing = (state.bearing + value) % 360;
+            state.waypoint = rotate(state.waypoint, value);
+        }
+        'F' => {
+            let (fx, fy) = direction(state.bearing);
+
+            state.ship = (state.ship.0 + fx * value, state.ship.1 + fy * value);
+            state.waypoint_ship = (state.waypoint_ship.0 + state.waypoint.0 * value,
+                state.waypoint_ship.1 + state.waypoint.1 * value);
+        }
+        _ => {}
+    }
+
+    state
+}
@@ -0,0 +1,39 @@
+//! Bus notes with distinct prime bus IDs, so the buses always line up
+//!
+//! Settings: buses (9)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{GenRng, Settings, MAX_ATTEMPTS};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("buses", 9, 1..=20)?;
+
+    let primes: Vec<u64> = (13..1000).filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)).collect();
+
+    for _ in 0..MAX_ATTEMPTS {
+        let ids: Vec<u64> = primes.choose_multiple(rng, count).copied().collect();
+
+        // The solution has to fit in a signed 128 bit integer
+        if ids.iter().try_fold(1_i128, |product, &id| product.checked_mul(id as i128)).is_none() {
+            continue
+        }
+
+        // The first slot always has a bus
+        let mut slots = vec!["x".to_string(); count * rng.gen_range(4..=8)];
+        let mut positions: Vec<usize> = (1..slots.len()).collect();
+        positions.shuffle(rng);
+        positions[0] = 0;
+
+        for (&pos, id) in positions.iter().zip(ids) {
+            slots[pos] = id.to_string();
+        }
+
+        let depart = rng.gen_range(1_000_000..=1_010_000);
+
+        return Ok(format!("{}\n{}\n", depart, slots.join(",")))
+    }
+
+    Err("couldn't find bus IDs with a small enough product".to_string())
+}
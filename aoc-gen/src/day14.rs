@@ -0,0 +1,35 @@
+//! Docking program of masks each followed by a few memory writes
+//!
+//! Settings: masks (100), floating (9, the most X bits in a mask)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("masks", 100, 1..=10_000)?;
+    let floating = settings.get_range("floating", 9, 0..=12)?;
+
+    let mut input = String::new();
+
+    for _ in 0..count {
+        let mut mask: Vec<char> = (0..36).map(|_| if rng.gen() { '1' } else { '0' }).collect();
+
+        let bits: Vec<usize> = (0..36).collect();
+
+        let xs = rng.gen_range(0..=floating);
+
+        for &bit in bits.choose_multiple(rng, xs) {
+            mask[bit] = 'X';
+        }
+
+        input += &format!("mask = {}\n", mask.into_iter().collect::<String>());
+
+        for _ in 0..rng.gen_range(1..=6) {
+            input += &format!("mem[{}] = {}\n", rng.gen_range(0..65536), rng.gen_range(0..1 << 30));
+        }
+    }
+
+    Ok(input)
+}
@@ -0,0 +1,18 @@
+//! Starting numbers for the memory game
+//!
+//! Settings: numbers (7)
+
+use rand::seq::SliceRandom;
+
+use crate::{GenRng, Settings};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("numbers", 7, 1..=20)?;
+
+    let numbers: Vec<String> = (0..20).collect::<Vec<u32>>()
+        .choose_multiple(rng, count)
+        .map(|n| n.to_string())
+        .collect();
+
+    Ok(format!("{}\n", numbers.join(",")))
+}
@@ -0,0 +1,97 @@
+//! Ticket notes where the fields can be matched to ticket positions in only
+//! one way. Each field's rule has a gap, and the column for the field ranked
+//! n holds values in the gaps of the fields ranked below it, leaving a chain
+//! of candidates for elimination
+//!
+//! Settings: fields (20), tickets (240), invalid (0.25, the fraction of nearby tickets with an invalid value)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+const FIELDS: [&str; 20] = [
+    "departure location", "departure station", "departure platform", "departure track", "departure date",
+    "departure time", "arrival location", "arrival station", "arrival platform", "arrival track", "class",
+    "duration", "price", "route", "row", "seat", "train", "type", "wagon", "zone"
+];
+
+/// Values valid for every field, apart from the gaps
+const CORE: (u32, u32) = (50, 949);
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("fields", 20, 1..=FIELDS.len())?;
+    let tickets = settings.get_range("tickets", 240, count..=10_000)?;
+    let invalid = settings.get_range("invalid", 0.25, 0.0..=0.9)?;
+
+    // Rule gaps share out the core values without overlapping
+    let slot = (CORE.1 - CORE.0 + 1) / count as u32;
+
+    let gaps: Vec<(u32, u32)> = (0..count as u32).map(|f| {
+        let len = rng.gen_range(1..=(slot - 1).min(20));
+        let start = CORE.0 + f * slot + rng.gen_range(0..slot - len);
+
+        (start, start + len - 1)
+    }).collect();
+
+    let in_gap = |v: u32| gaps.iter().any(|&(s, e)| v >= s && v <= e);
+
+    let core_value = |rng: &mut GenRng| loop {
+        let v = rng.gen_range(CORE.0..=CORE.1);
+
+        if !in_gap(v) {
+            break v
+        }
+    };
+
+    let mut input = String::new();
+
+    for (f, &(start, end)) in gaps.iter().enumerate() {
+        input += &format!("{}: {}-{} or {}-{}\n", FIELDS[f], rng.gen_range(25..CORE.0), start - 1,
+            end + 1, rng.gen_range(CORE.1 + 1..975));
+    }
+
+    // The field for each position, in rank order
+    let mut ranked: Vec<usize> = (0..count).collect();
+    ranked.shuffle(rng);
+
+    let mut positions: Vec<usize> = (0..count).collect();
+    positions.shuffle(rng);
+
+    let mut valid: Vec<Vec<u32>> = (0..tickets).map(|_| (0..count).map(|_| core_value(rng)).collect()).collect();
+
+    // Rule out the lower ranked fields for each position
+    for (rank, &pos) in positions.iter().enumerate() {
+        let mut holders: Vec<usize> = (0..tickets).collect();
+        holders.shuffle(rng);
+
+        for (&lower, &ticket) in ranked[..rank].iter().zip(&holders) {
+            let (start, end) = gaps[lower];
+
+            valid[ticket][pos] = rng.gen_range(start..=end);
+        }
+    }
+
+    let your: Vec<u32> = (0..count).map(|_| core_value(rng)).collect();
+
+    input += &format!("\nyour ticket:\n{}\n\nnearby tickets:\n", join(&your));
+
+    for ticket in valid {
+        // Mix in tickets with a value no field allows
+        while rng.gen_bool(invalid) {
+            let mut bad = (0..count).map(|_| core_value(rng)).collect::<Vec<_>>();
+
+            bad[rng.gen_range(0..count)] = if rng.gen() { rng.gen_range(0..25) } else { rng.gen_range(975..1000) };
+
+            input += &format!("{}\n", join(&bad));
+        }
+
+        input += &format!("{}\n", join(&ticket));
+    }
+
+    Ok(input)
+}
+
+fn join(values: &[u32]) -> String {
+    values.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",")
+}
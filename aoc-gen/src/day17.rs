@@ -0,0 +1,21 @@
+//! Initial slice of Conway cubes
+//!
+//! Settings: size (8), active (0.5, the fraction of active cubes)
+
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let size = settings.get_range("size", 8, 1..=32)?;
+    let active = settings.get_range("active", 0.5, 0.0..=1.0)?;
+
+    let mut input = String::new();
+
+    for _ in 0..size {
+        input.extend((0..size).map(|_| if rng.gen_bool(active) { '#' } else { '.' }));
+        input.push('\n');
+    }
+
+    Ok(input)
+}
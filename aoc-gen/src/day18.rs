@@ -0,0 +1,126 @@
+//! Homework expressions of single digits. Expressions whose value under either
+//! set of precedence rules is too big are replaced
+//!
+//! Settings: expressions (375)
+
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+/// Largest value of an expression, keeping the total well inside 64 bits
+const LIMIT: u64 = 1_000_000_000_000;
+
+enum Term {
+    Number(u64),
+    Group(Expression)
+}
+
+/// Terms separated by '+' or '*'
+struct Expression {
+    terms: Vec<Term>,
+    ops: Vec<char>
+}
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("expressions", 375, 1..=100_000)?;
+
+    let mut input = String::new();
+    let mut made = 0;
+
+    while made < count {
+        let expression = random_expression(rng, 0);
+
+        let small = |value: Option<u64>| value.is_some_and(|v| v <= LIMIT);
+
+        if small(evaluate(&expression, false)) && small(evaluate(&expression, true)) {
+            input += &format!("{}\n", render(&expression));
+            made += 1;
+        }
+    }
+
+    Ok(input)
+}
+
+fn random_expression(rng: &mut GenRng, depth: usize) -> Expression {
+    let len = rng.gen_range(2..=6);
+
+    let terms = (0..len).map(|_| {
+        if depth < 3 && rng.gen_bool(0.25) {
+            Term::Group(random_expression(rng, depth + 1))
+        } else {
+            Term::Number(rng.gen_range(2..=9))
+        }
+    }).collect();
+
+    let ops = (1..len).map(|_| if rng.gen() { '+' } else { '*' }).collect();
+
+    Expression { terms, ops }
+}
+
+/// Evaluates left to right, or with addition first. None on overflow
+fn evaluate(expression: &Expression, add_first: bool) -> Option<u64> {
+    let value = |term: &Term| match term {
+        Term::Number(n) => Some(*n),
+        Term::Group(e) => evaluate(e, add_first)
+    };
+
+    if add_first {
+        // Product of the sums between the multiplications
+        let mut product = 1u64;
+        let mut sum = value(&expression.terms[0])?;
+
+        for (op, term) in expression.ops.iter().zip(&expression.terms[1..]) {
+            match op {
+                '+' => sum = sum.checked_add(value(term)?)?,
+                _ => {
+                    product = product.checked_mul(sum)?;
+                    sum = value(term)?;
+                }
+            }
+        }
+
+        product.checked_mul(sum)
+    } else {
+        let mut result = value(&expression.terms[0])?;
+
+        for (op, term) in expression.ops.iter().zip(&expression.terms[1..]) {
+            result = match op {
+                '+' => result.checked_add(value(term)?)?,
+                _ => result.checked_mul(value(term)?)?
+            };
+        }
+
+        Some(result)
+    }
+}
+
+fn render(expression: &Expression) -> String {
+    let term = |term: &Term| match term {
+        Term::Number(n) => n.to_string(),
+        Term::Group(e) => format!("({})", render(e))
+    };
+
+    let mut text = term(&expression.terms[0]);
+
+    for (op, t) in expression.ops.iter().zip(&expression.terms[1..]) {
+        text += &format!(" {} {}", op, term(t));
+    }
+
+    text
+}
+
+#[test]
+fn evaluate_test() {
+    // 2 * 3 + (4 * 5)
+    let expression = Expression {
+        terms: vec![Term::Number(2), Term::Number(3), Term::Group(Expression {
+            terms: vec![Term::Number(4), Term::Number(5)],
+            ops: vec!['*']
+        })],
+        ops: vec!['*', '+']
+    };
+
+    assert_eq!("2 * 3 + (4 * 5)", render(&expression));
+    assert_eq!(Some(26), evaluate(&expression, false));
+    assert_eq!(Some(46), evaluate(&expression, true));
+}
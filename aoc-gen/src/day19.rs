@@ -0,0 +1,154 @@
+//! Message rules and messages. Every word of a given length over 'a' and 'b'
+//! is matched by exactly one of rules 42 and 31, each built as a tree
+//! branching on the next letter. Rule 0 is "8 11", so the part 2 loops apply
+//!
+//! Settings: length (5, the length of the words matched by rules 42 and 31), messages (400)
+
+use std::collections::HashMap;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+type RuleNum = u8;
+
+/// Rule numbers with fixed meanings
+const FIXED: [RuleNum; 5] = [0, 8, 11, 31, 42];
+
+struct Grammar {
+    /// Rule number for each letter
+    letters: [RuleNum; 2],
+    /// Rule numbers not used yet
+    free: Vec<RuleNum>,
+    /// Rule numbers already built for sets of words
+    built: HashMap<Vec<Vec<u8>>, RuleNum>,
+    rules: Vec<String>
+}
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let length = settings.get_range("length", 5, 2..=6)?;
+    let count = settings.get_range("messages", 400, 1..=100_000)?;
+
+    // Every word of the length, as 0 for 'a' and 1 for 'b'
+    let words: Vec<Vec<u8>> = (0..1 << length)
+        .map(|n: u32| (0..length).rev().map(|bit| (n >> bit & 1) as u8).collect())
+        .collect();
+
+    let (mut rule42, mut rule31) = (Vec::new(), Vec::new());
+
+    while rule42.is_empty() || rule31.is_empty() {
+        rule42.clear();
+        rule31.clear();
+
+        for word in &words {
+            if rng.gen() { rule42.push(word.clone()) } else { rule31.push(word.clone()) }
+        }
+    }
+
+    let mut free: Vec<RuleNum> = (1..=RuleNum::MAX).filter(|n| !FIXED.contains(n)).collect();
+    free.shuffle(rng);
+
+    let letters = [free.pop().unwrap(), free.pop().unwrap()];
+
+    let mut grammar = Grammar {
+        letters,
+        free,
+        built: HashMap::new(),
+        rules: vec![
+            "0: 8 11".to_string(),
+            "8: 42".to_string(),
+            "11: 42 31".to_string(),
+            format!("{}: \"a\"", letters[0]),
+            format!("{}: \"b\"", letters[1])
+        ]
+    };
+
+    grammar.build(&rule42, Some(42));
+    grammar.build(&rule31, Some(31));
+
+    grammar.rules.shuffle(rng);
+
+    let mut input = grammar.rules.join("\n");
+    input += "\n\n";
+
+    for _ in 0..count {
+        let mut message: Vec<u8> = Vec::new();
+
+        match rng.gen_range(0..4) {
+            // Some 42s then fewer 31s, valid in part 2
+            0 | 1 => {
+                let n31 = rng.gen_range(1..=3);
+
+                for _ in 0..rng.gen_range(n31 + 1..=5) {
+                    message.extend(rule42.choose(rng).unwrap());
+                }
+
+                for _ in 0..n31 {
+                    message.extend(rule31.choose(rng).unwrap());
+                }
+            }
+            // Too many 31s
+            2 => {
+                let n42 = rng.gen_range(1..=3);
+
+                for _ in 0..n42 {
+                    message.extend(rule42.choose(rng).unwrap());
+                }
+
+                for _ in 0..rng.gen_range(n42..=4) {
+                    message.extend(rule31.choose(rng).unwrap());
+                }
+            }
+            // Random letters
+            _ => message.extend((0..rng.gen_range(1..=length * 6)).map(|_| rng.gen_range(0..2)))
+        }
+
+        input.extend(message.iter().map(|&l| if l == 0 { 'a' } else { 'b' }));
+        input.push('\n');
+    }
+
+    Ok(input)
+}
+
+impl Grammar {
+    /// Builds a rule matching a set of words of equal length, reusing rules
+    /// for sets already seen. Returns the rule's number
+    fn build(&mut self, words: &[Vec<u8>], number: Option<RuleNum>) -> RuleNum {
+        if let Some(&built) = self.built.get(words) {
+            return built
+        }
+
+        // A single letter is matched by the letter's own rule
+        if number.is_none() && words.len() == 1 && words[0].len() == 1 {
+            return self.letters[words[0][0] as usize]
+        }
+
+        let mut branches = Vec::new();
+
+        for letter in 0..2 {
+            let rest: Vec<Vec<u8>> = words.iter()
+                .filter(|w| w[0] == letter)
+                .map(|w| w[1..].to_vec())
+                .collect();
+
+            if rest.is_empty() {
+                continue
+            }
+
+            if rest[0].is_empty() {
+                branches.push(self.letters[letter as usize].to_string());
+            } else {
+                let next = self.build(&rest, None);
+                branches.push(format!("{} {}", self.letters[letter as usize], next));
+            }
+        }
+
+        let number = number.unwrap_or_else(|| self.free.pop().expect("Rule numbers used up"));
+
+        self.rules.push(format!("{}: {}", number, branches.join(" | ")));
+        self.built.insert(words.to_vec(), number);
+
+        number
+    }
+}
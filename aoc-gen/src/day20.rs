@@ -0,0 +1,143 @@
+//! Jigsaw tiles cut from a random image with sea monsters in it. Each tile
+//! edge pattern is used by at most one pair of neighbouring tiles, and none
+//! read the same both ways, so the tiles only fit together one way. The tiles
+//! are then randomly rotated, flipped and shuffled
+//!
+//! Settings: size (12, tiles along each side), monsters (20), rough (0.35, the fraction of rough water)
+
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use aoc_common::Grid;
+
+use crate::{GenRng, Settings, MAX_ATTEMPTS};
+
+const TILE_DIM: usize = 10;
+
+/// Image pixels in each tile, without the borders
+const INNER_DIM: usize = TILE_DIM - 2;
+
+const MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   "
+];
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    // Edges are 10 bits, limiting the number of distinct ones
+    let size = settings.get_range("size", 12, 2..=15)?;
+    let monsters = settings.get_range("monsters", 20, 0..=100)?;
+    let rough = settings.get_range("rough", 0.35, 0.0..=1.0)?;
+
+    let image = make_image(rng, size * INNER_DIM, monsters, rough)?;
+
+    // The pixels on the tile corners are shared by up to four tiles
+    let corners = Grid::from_fn(size + 1, size + 1, |_, _| rng.gen::<bool>());
+
+    let mut used = HashSet::new();
+
+    // Edges run left to right and top to bottom, between the corner pixels
+    let mut edge = |rng: &mut GenRng, first: bool, last: bool| -> Result<Vec<bool>, String> {
+        for _ in 0..MAX_ATTEMPTS {
+            let mut pixels = vec![first];
+            pixels.extend((0..INNER_DIM).map(|_| rng.gen::<bool>()));
+            pixels.push(last);
+
+            let reversed: Vec<bool> = pixels.iter().rev().copied().collect();
+
+            if pixels != reversed && !used.contains(&reversed) && used.insert(pixels.clone()) {
+                return Ok(pixels)
+            }
+        }
+
+        Err("ran out of tile edges".to_string())
+    };
+
+    let mut horiz = Grid::new(size, size + 1, Vec::new());
+    let mut vert = Grid::new(size + 1, size, Vec::new());
+
+    for y in 0..=size {
+        for x in 0..size {
+            horiz[(x, y)] = edge(rng, corners[(x, y)], corners[(x + 1, y)])?;
+        }
+    }
+
+    for y in 0..size {
+        for x in 0..=size {
+            vert[(x, y)] = edge(rng, corners[(x, y)], corners[(x, y + 1)])?;
+        }
+    }
+
+    let mut ids: Vec<u16> = (1000..=9999).collect();
+    ids.shuffle(rng);
+
+    let mut tiles = Vec::new();
+
+    for ty in 0..size {
+        for tx in 0..size {
+            let tile = Grid::from_fn(TILE_DIM, TILE_DIM, |x, y| {
+                match (x, y) {
+                    (_, 0) => horiz[(tx, ty)][x],
+                    (_, 9) => horiz[(tx, ty + 1)][x],
+                    (0, _) => vert[(tx, ty)][y],
+                    (9, _) => vert[(tx + 1, ty)][y],
+                    _ => image[(tx * INNER_DIM + x - 1, ty * INNER_DIM + y - 1)]
+                }
+            });
+
+            let orientations = tile.orientations();
+            let tile = orientations.choose(rng).unwrap();
+
+            tiles.push(format!("Tile {}:\n{}", ids[tiles.len()], tile.render(|&p| if p { '#' } else { '.' })));
+        }
+    }
+
+    tiles.shuffle(rng);
+
+    Ok(tiles.join("\n"))
+}
+
+/// Random image with sea monsters placed so they don't overlap
+fn make_image(rng: &mut GenRng, dim: usize, monsters: usize, rough: f64) -> Result<Grid<bool>, String> {
+    let mut image = Grid::from_fn(dim, dim, |_, _| rng.gen_bool(rough));
+
+    let (width, height) = (MONSTER[0].len(), MONSTER.len());
+
+    if monsters > 0 && dim < width {
+        Err("image too small for monsters".to_string())?
+    }
+
+    let mut placed: Vec<(usize, usize)> = Vec::new();
+
+    for _ in 0..monsters {
+        let mut attempts = 0;
+
+        let (mx, my) = loop {
+            let pos = (rng.gen_range(0..=dim - width), rng.gen_range(0..=dim - height));
+
+            if placed.iter().all(|&(px, py)| px.abs_diff(pos.0) >= width || py.abs_diff(pos.1) >= height) {
+                break pos
+            }
+
+            attempts += 1;
+
+            if attempts == MAX_ATTEMPTS {
+                Err(format!("no room for {} monsters", monsters))?
+            }
+        };
+
+        for (y, row) in MONSTER.iter().enumerate() {
+            for (x, c) in row.char_indices() {
+                if c == '#' {
+                    image[(mx + x, my + y)] = true;
+                }
+            }
+        }
+
+        placed.push((mx, my));
+    }
+
+    Ok(image)
+}
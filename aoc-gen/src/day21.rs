@@ -0,0 +1,93 @@
+//! Food list where each allergen is in one ingredient. Foods are added until
+//! the allergens can only be matched to ingredients one way
+//!
+//! Settings: foods (40), allergens (8), ingredients (200)
+
+use std::collections::BTreeSet;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use aoc_common::matching::{self, Assignment};
+
+use crate::{GenRng, Settings};
+
+const ALLERGENS: [&str; 10] = ["dairy", "eggs", "fish", "gluten", "nuts", "peanuts", "sesame", "shellfish", "soy", "wheat"];
+
+struct Food {
+    ingredients: Vec<usize>,
+    allergens: Vec<usize>
+}
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let min_foods = settings.get_range("foods", 40, 1..=10_000)?;
+    let allergen_count = settings.get_range("allergens", 8, 1..=ALLERGENS.len())?;
+    let ingredient_count = settings.get_range("ingredients", 200, allergen_count + 1..=10_000)?;
+
+    let mut allergens: Vec<&str> = ALLERGENS.choose_multiple(rng, allergen_count).copied().collect();
+    allergens.sort_unstable();
+
+    let mut names = BTreeSet::new();
+
+    while names.len() < ingredient_count {
+        let len = rng.gen_range(3..=8);
+        names.insert((0..len).map(|_| rng.gen_range(b'a'..=b'z') as char).collect::<String>());
+    }
+
+    let mut names: Vec<String> = names.into_iter().collect();
+    names.shuffle(rng);
+
+    // The first ingredients hold the allergens
+    let safe: Vec<usize> = (allergen_count..ingredient_count).collect();
+
+    let mut foods: Vec<Food> = Vec::new();
+
+    while foods.len() < min_foods || !unique(&foods, allergen_count, ingredient_count) {
+        if foods.len() >= min_foods * 10 + 100 {
+            Err("couldn't make the allergens unique".to_string())?
+        }
+
+        let listing = rng.gen_range(1..=3.min(allergen_count));
+
+        let mut listed: Vec<usize> = (0..allergen_count).collect::<Vec<_>>()
+            .choose_multiple(rng, listing)
+            .copied()
+            .collect();
+        listed.sort_unstable();
+
+        // Listed allergens are always present, others only sometimes
+        let mut ingredients: Vec<usize> = (0..allergen_count).filter(|a| listed.contains(a) || rng.gen_bool(0.3)).collect();
+
+        let extra = rng.gen_range(1..=safe.len().min(60));
+        ingredients.extend(safe.choose_multiple(rng, extra));
+        ingredients.shuffle(rng);
+
+        foods.push(Food { ingredients, allergens: listed });
+    }
+
+    let lines: Vec<String> = foods.iter().map(|food| {
+        let ingredients: Vec<&str> = food.ingredients.iter().map(|&i| names[i].as_str()).collect();
+        let listed: Vec<&str> = food.allergens.iter().map(|&a| allergens[a]).collect();
+
+        format!("{} (contains {})\n", ingredients.join(" "), listed.join(", "))
+    }).collect();
+
+    Ok(lines.concat())
+}
+
+/// Whether every allergen is listed and there is only one way to match them to ingredients
+fn unique(foods: &[Food], allergens: usize, ingredients: usize) -> bool {
+    let candidates: Vec<Vec<usize>> = (0..allergens).map(|a| {
+        let mut listing = foods.iter().filter(|f| f.allergens.contains(&a));
+
+        match listing.next() {
+            Some(first) => listing.fold(first.ingredients.clone(), |mut cands, food| {
+                cands.retain(|i| food.ingredients.contains(i));
+                cands
+            }),
+            None => Vec::new()
+        }
+    }).collect();
+
+    matches!(matching::assign(&candidates, ingredients), Assignment::Unique(_))
+}
@@ -0,0 +1,53 @@
+//! Shuffled space cards dealt between two players. Deals where a game of
+//! plain combat goes on too long are redealt, as it has no loop rule
+//!
+//! Settings: cards (50)
+
+use std::collections::VecDeque;
+
+use rand::seq::SliceRandom;
+
+use crate::{GenRng, Settings, MAX_ATTEMPTS};
+
+/// Rounds of plain combat allowed
+const MAX_ROUNDS: usize = 100_000;
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("cards", 50, 2..=200)?;
+
+    for _ in 0..MAX_ATTEMPTS {
+        let mut cards: Vec<u32> = (1..=count).collect();
+        cards.shuffle(rng);
+
+        let (player1, player2) = cards.split_at(count as usize / 2);
+
+        if !finishes(player1, player2) {
+            continue
+        }
+
+        let deck = |cards: &[u32]| cards.iter().map(|c| format!("{}\n", c)).collect::<String>();
+
+        return Ok(format!("Player 1:\n{}\nPlayer 2:\n{}", deck(player1), deck(player2)))
+    }
+
+    Err("couldn't deal a game which finishes".to_string())
+}
+
+/// Whether a game of plain combat finishes within MAX_ROUNDS
+fn finishes(player1: &[u32], player2: &[u32]) -> bool {
+    let mut hands: [VecDeque<u32>; 2] = [player1.iter().copied().collect(), player2.iter().copied().collect()];
+
+    for _ in 0..MAX_ROUNDS {
+        let (c1, c2) = match (hands[0].pop_front(), hands[1].pop_front()) {
+            (Some(c1), Some(c2)) => (c1, c2),
+            _ => return true
+        };
+
+        let winner = if c1 > c2 { 0 } else { 1 };
+
+        hands[winner].push_back(c1.max(c2));
+        hands[winner].push_back(c1.min(c2));
+    }
+
+    false
+}
@@ -0,0 +1,17 @@
+//! Cup labels
+//!
+//! Settings: cups (9)
+
+use rand::seq::SliceRandom;
+
+use crate::{GenRng, Settings};
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    // Labels are single digits
+    let count = settings.get_range("cups", 9, 5..=9)?;
+
+    let mut cups: Vec<String> = (1..=count).map(|c: u32| c.to_string()).collect();
+    cups.shuffle(rng);
+
+    Ok(format!("{}\n", cups.concat()))
+}
@@ -0,0 +1,26 @@
+//! Directions to tiles on the hexagonal floor
+//!
+//! Settings: tiles (380)
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::{GenRng, Settings};
+
+const DIRECTIONS: [&str; 6] = ["e", "se", "sw", "w", "nw", "ne"];
+
+pub fn generate(rng: &mut GenRng, settings: &Settings) -> Result<String, String> {
+    let count = settings.get_range("tiles", 380, 1..=100_000)?;
+
+    let mut input = String::new();
+
+    for _ in 0..count {
+        for _ in 0..rng.gen_range(10..=25) {
+            input += DIRECTIONS.choose(rng).unwrap();
+        }
+
+        input.push('\n');
+    }
+
+    Ok(input)
+}
@@ -0,0 +1,23 @@
+//! Card and door public keys from random loop sizes
+//!
+//! Settings: none
+
+use rand::Rng;
+
+use aoc_common::numtheory::modpow;
+
+use crate::{GenRng, Settings};
+
+const SUBJECT: i64 = 7;
+
+const MODULUS: i64 = 20201227;
+
+pub fn generate(rng: &mut GenRng, _settings: &Settings) -> Result<String, String> {
+    let mut key = || {
+        let loop_size = rng.gen_range(1000..MODULUS - 1);
+
+        modpow(SUBJECT, loop_size, MODULUS).map_err(|e| e.to_string())
+    };
+
+    Ok(format!("{}\n{}\n", key()?, key()?))
+}
@@ -0,0 +1,199 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+/// Random number generator used by the generators. ChaCha produces the same
+/// sequence for a seed on every platform
+pub type GenRng = ChaCha8Rng;
+
+/// Generates a day's input from the random number generator and settings
+type GenFn = fn(&mut GenRng, &Settings) -> Result<String, String>;
+
+const GENERATORS: [GenFn; 25] = [
+    day01::generate,
+    day02::generate,
+    day03::generate,
+    day04::generate,
+    day05::generate,
+    day06::generate,
+    day07::generate,
+    day08::generate,
+    day09::generate,
+    day10::generate,
+    day11::generate,
+    day12::generate,
+    day13::generate,
+    day14::generate,
+    day15::generate,
+    day16::generate,
+    day17::generate,
+    day18::generate,
+    day19::generate,
+    day20::generate,
+    day21::generate,
+    day22::generate,
+    day23::generate,
+    day24::generate,
+    day25::generate,
+];
+
+/// Number of times a generator retries a random choice which doesn't give a
+/// usable puzzle before giving up
+const MAX_ATTEMPTS: usize = 1000;
+
+/// Generator settings given as name=value. Each day has its own names, with
+/// defaults giving inputs the size of the real puzzle inputs
+#[derive(Debug, Default)]
+pub struct Settings {
+    values: BTreeMap<String, String>,
+    used: RefCell<BTreeSet<String>>
+}
+
+impl Settings {
+    pub fn new() -> Settings {
+        Default::default()
+    }
+
+    /// Adds a setting in name=value form
+    pub fn add(&mut self, setting: &str) -> Result<(), String> {
+        match setting.split_once('=') {
+            Some((name, value)) if !name.is_empty() => {
+                self.values.insert(name.to_string(), value.to_string());
+                Ok(())
+            }
+            _ => Err(format!("Invalid setting '{}', expecting name=value", setting))
+        }
+    }
+
+    /// Looks up a setting, returning default if it isn't given
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        self.used.borrow_mut().insert(name.to_string());
+
+        match self.values.get(name) {
+            Some(value) => value.parse().map_err(|_| format!("Invalid value '{}' for setting '{}'", value, name)),
+            None => Ok(default)
+        }
+    }
+
+    /// Looks up a setting which must be within range
+    pub fn get_range<T>(&self, name: &str, default: T, range: RangeInclusive<T>) -> Result<T, String>
+    where T: FromStr + PartialOrd + Display {
+        let value = self.get(name, default)?;
+
+        if !range.contains(&value) {
+            Err(format!("Setting '{}' must be {} to {}", name, range.start(), range.end()))?
+        }
+
+        Ok(value)
+    }
+
+    /// Settings given which weren't looked up
+    fn unused(&self) -> Vec<&str> {
+        let used = self.used.borrow();
+
+        self.values.keys().filter(|name| !used.contains(*name)).map(|name| name.as_str()).collect()
+    }
+}
+
+/// Generates a random input for a day. The same seed and settings always give
+/// the same input
+pub fn generate(day: u8, seed: u64, settings: &Settings) -> Result<String, Box<dyn Error>> {
+    let generator = match day {
+        1..=25 => GENERATORS[day as usize - 1],
+        _ => Err(format!("No generator for day {}", day))?
+    };
+
+    settings.used.borrow_mut().clear();
+
+    let mut rng = GenRng::seed_from_u64(seed);
+
+    let input = generator(&mut rng, settings).map_err(|e| format!("Day {}: {}", day, e))?;
+
+    let unused = settings.unused();
+
+    if !unused.is_empty() {
+        Err(format!("Day {}: unknown settings {}", day, unused.join(", ")))?
+    }
+
+    Ok(input)
+}
+
+/// Joins values one per line
+fn lines<T: Display>(values: &[T]) -> String {
+    values.iter().map(|v| format!("{}\n", v)).collect()
+}
+
+#[test]
+fn settings_test() {
+    let mut settings = Settings::new();
+
+    settings.add("passports=10").unwrap();
+    settings.add("invalid=0.5").unwrap();
+    assert!(settings.add("passports").is_err());
+    assert!(settings.add("=3").is_err());
+
+    assert_eq!(Ok(10), settings.get("passports", 290));
+    assert_eq!(Ok(0.1), settings.get("missing", 0.1));
+    assert_eq!(vec!["invalid"], settings.unused());
+
+    assert!(settings.get_range("invalid", 0.0, 0.0..=0.4).is_err());
+    assert!(settings.get::<u8>("invalid", 0).is_err());
+}
+
+#[test]
+fn generate_test() {
+    // Same seed gives the same input, different ones differ
+    for day in 1..=25 {
+        let mut settings = Settings::new();
+
+        // Settling the full size seat layout is slow unoptimised
+        if day == 11 {
+            settings.add("width=30").unwrap();
+            settings.add("height=30").unwrap();
+        }
+
+        let input = generate(day, 1, &settings).unwrap();
+
+        assert_eq!(input, generate(day, 1, &settings).unwrap());
+        assert_ne!(input, generate(day, 2, &settings).unwrap(), "Day {} ignores the seed", day);
+    }
+
+    let mut settings = Settings::new();
+    settings.add("passports=10").unwrap();
+
+    assert!(generate(4, 1, &settings).is_ok());
+    assert_eq!("Day 5: unknown settings passports", generate(5, 1, &settings).unwrap_err().to_string());
+    assert!(generate(26, 1, &settings).is_err());
+}
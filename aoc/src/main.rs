@@ -0,0 +1,817 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::answers::{self, Verdict};
+use aoc_common::bench::{self, Readable};
+use aoc_common::diag::{self, Report, Stat};
+use aoc_common::parallel::{self, Outcome, PartRun};
+use aoc_common::watch::Watched;
+use aoc_common::{Day, Input, Json, ParseError};
+
+mod serve;
+
+const DAYS: [Day; 25] = [
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+    Day::of::<day16::Day16>(),
+    Day::of::<day17::Day17>(),
+    Day::of::<day18::Day18>(),
+    Day::of::<day19::Day19>(),
+    Day::of::<day20::Day20>(),
+    Day::of::<day21::Day21>(),
+    Day::of::<day22::Day22>(),
+    Day::of::<day23::Day23>(),
+    Day::of::<day24::Day24>(),
+    Day::of::<day25::Day25>(),
+];
+
+const USAGE: &str = "Usage: aoc <day> [<part>] [--input <file>|-] [--json|--parallel [--threads <n>]]
+       aoc all [--json|--parallel [--threads <n>]]
+       aoc verify [<day>] [--answers <file>]
+       aoc bench [<day>] [--input <file>|-] [--repeat <n>] [--output <file>] [--baseline <file>] [--threshold <percent>]
+       aoc gen <day> [--seed <n>] [--set <name>=<value>]... [--output <file>]
+       aoc watch <day> [--input <file>]
+       aoc serve [--port <n>] [--threads <n>] [--time-limit <secs>] [--memory-limit <MB>]";
+
+/// Number of times each phase is run when benchmarking
+const DEFAULT_REPEAT: usize = 5;
+
+/// Percentage slowdown against the baseline which is flagged
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Seed for generated inputs
+const DEFAULT_SEED: u64 = 1;
+
+/// How often watched files are checked for changes
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Port the solve service listens on
+const DEFAULT_PORT: u16 = 2020;
+
+/// Number of requests the solve service handles at once
+const DEFAULT_WORKERS: usize = 4;
+
+/// Time allowed to solve each request in seconds
+const DEFAULT_TIME_LIMIT: f64 = 10.0;
+
+/// Memory allowed to solve each request in megabytes
+const DEFAULT_MEMORY_LIMIT: u64 = 1024;
+
+/// Largest input accepted by the solve service
+const MAX_INPUT_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Mode {
+    #[default]
+    Run,
+    Verify,
+    Bench,
+    Gen,
+    Watch,
+    Serve
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    mode: Mode,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    json: bool,
+    answers: Option<String>,
+    repeat: Option<usize>,
+    output: Option<String>,
+    baseline: Option<String>,
+    threshold: Option<f64>,
+    seed: Option<u64>,
+    settings: Vec<String>,
+    parallel: bool,
+    threads: Option<usize>,
+    port: Option<u16>,
+    time_limit: Option<f64>,
+    memory_limit: Option<u64>
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let options = parse_args(&args)?;
+
+    let selected: Vec<&Day> = DAYS.iter().filter(|d| {
+        options.day.is_none_or(|day| d.day == day)
+    }).collect();
+
+    // Only plain runs show the solutions' debug output
+    diag::set_quiet(options.mode != Mode::Run || options.json);
+
+    match options.mode {
+        Mode::Run if options.json => run_days_json(&selected, &options),
+        Mode::Run if options.parallel => run_parallel(&selected, &options),
+        Mode::Run => run_days(&selected, &options),
+        Mode::Verify => verify(&selected, options.answers.as_deref()),
+        Mode::Bench => bench(&selected, &options),
+        Mode::Gen => gen(&selected, &options),
+        Mode::Watch => watch(selected[0], &options),
+        Mode::Serve => serve(&options)
+    }
+}
+
+/// The parts of a day to run
+fn parts(d: &Day, options: &Options) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    match options.part {
+        Some(part) if part > d.parts => Err(format!("Day {} has no part {}", d.day, part))?,
+        Some(part) => Ok(vec![part]),
+        None => Ok((1..=d.parts).collect())
+    }
+}
+
+fn run_days(selected: &[&Day], options: &Options) -> Result<(), Box<dyn std::error::Error>> {
+    for d in selected {
+        let parts = parts(d, options)?;
+
+        println!("== Day {} ==", d.day);
+
+        let input = Input::new(d.day, options.input.as_deref());
+
+        for answer in d.run_input(&input, &parts)? {
+            println!("Part {}: {}", answer.part, answer.value);
+        }
+
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Runs the selected days printing a JSON document with the answers, statistics,
+/// warnings and timings. Debug output from the solutions is suppressed
+fn run_days_json(selected: &[&Day], options: &Options) -> Result<(), Box<dyn std::error::Error>> {
+    let mut days = Vec::new();
+    let mut failed = 0;
+
+    for d in selected {
+        let parts = parts(d, options)?;
+
+        let input = Input::new(d.day, options.input.as_deref());
+
+        let (day_json, ok) = day_json(d, &input, &parts);
+
+        if !ok {
+            failed += 1;
+        }
+
+        days.push(day_json);
+    }
+
+    println!("{}", Json::object(vec![("days", Json::Array(days))]));
+
+    if failed > 0 {
+        Err(format!("{} days failed", failed))?
+    }
+
+    Ok(())
+}
+
+/// Runs one day for JSON output. The flag is false if parsing or any part failed
+fn day_json(d: &Day, input: &Input, parts: &[u8]) -> (Json, bool) {
+    let mut members = vec![("day", Json::Int(d.day as i128))];
+
+    diag::take();
+
+    let start = Instant::now();
+    let parsed = d.parse_input(input);
+    let parse_time = start.elapsed();
+
+    let (name, model) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            members.push(("input", Json::Str(input.source.to_string())));
+            members.push(("error", Json::Str(e.to_string())));
+
+            if let Some(parse_error) = e.downcast_ref::<ParseError>() {
+                members.push(("parse_error", parse_error.to_json()));
+            }
+
+            return (Json::object(members), false)
+        }
+    };
+
+    members.push(("input", Json::Str(name)));
+    members.push(("parse_ns", Json::Int(parse_time.as_nanos() as i128)));
+    members.append(&mut report_json(diag::take()));
+
+    let mut ok = true;
+    let mut part_list = Vec::new();
+
+    for &part in parts {
+        let start = Instant::now();
+        let result = (d.solve)(model.as_ref(), part);
+        let solve_time = start.elapsed();
+
+        let mut part_members = vec![("part", Json::Int(part as i128))];
+
+        match result {
+            Ok(answer) => part_members.push(("answer", Json::Str(answer))),
+            Err(e) => {
+                part_members.push(("error", Json::Str(e.to_string())));
+                ok = false;
+            }
+        }
+
+        part_members.push(("time_ns", Json::Int(solve_time.as_nanos() as i128)));
+        part_members.append(&mut report_json(diag::take()));
+
+        part_list.push(Json::object(part_members));
+    }
+
+    members.push(("parts", Json::Array(part_list)));
+
+    (Json::object(members), ok)
+}
+
+fn report_json(report: Report) -> Vec<(&'static str, Json)> {
+    let stats = report.stats.into_iter().map(|(name, stat)| {
+        (name, match stat {
+            Stat::Int(n) => Json::Int(n),
+            Stat::Text(s) => Json::Str(s)
+        })
+    }).collect();
+
+    vec![
+        ("stats", Json::Object(stats)),
+        ("warnings", Json::Array(report.warnings.into_iter().map(Json::Str).collect()))
+    ]
+}
+
+/// Runs every selected day and part at once on a pool of threads, then prints
+/// each part's output and a summary table
+fn run_parallel(selected: &[&Day], options: &Options) -> Result<(), Box<dyn std::error::Error>> {
+    let mut tasks = Vec::new();
+
+    for d in selected {
+        tasks.extend(parts(d, options)?.into_iter().map(|part| (*d, part)));
+    }
+
+    let start = Instant::now();
+    let runs = parallel::run(&tasks, options.input.as_deref(), options.threads)?;
+    let elapsed = start.elapsed();
+
+    for run in runs.iter().filter(|r| !r.output.is_empty()) {
+        println!("== Day {} part {} ==", run.day, run.part);
+        print!("{}", run.output);
+        println!();
+    }
+
+    let rows: Vec<(&PartRun, &str, &str)> = runs.iter().map(|r| match &r.outcome {
+        Outcome::Answer(answer) => (r, answer.as_str(), "ok"),
+        Outcome::Error(e) => (r, e.as_str(), "ERROR"),
+        Outcome::Panic(message) => (r, message.as_str(), "PANIC")
+    }).collect();
+
+    let width = rows.iter().map(|(_, text, _)| text.chars().count()).max().unwrap_or(0).max(6);
+
+    println!("{:>3} {:>4} {:<width$} {:>12} Status", "Day", "Part", "Answer", "Time", width = width);
+
+    for (run, text, status) in &rows {
+        println!("{:>3} {:>4} {:<width$} {:>12} {}", run.day, run.part, text, Readable(run.time).to_string(), status, width = width);
+    }
+
+    let total: Duration = runs.iter().map(|r| r.time).sum();
+
+    println!();
+    println!("{} parts in {} elapsed, {} in total", runs.len(), Readable(elapsed), Readable(total));
+
+    let failed = rows.iter().filter(|(_, _, status)| *status != "ok").count();
+
+    if failed > 0 {
+        Err(format!("{} parts failed", failed))?
+    }
+
+    Ok(())
+}
+
+/// Checks the selected days against the answers registry
+fn verify(selected: &[&Day], answers_file: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+    let path = match answers_file {
+        Some(file) => PathBuf::from(file),
+        None => {
+            let paths = answers::search_paths();
+
+            match paths.iter().find(|p| p.is_file()) {
+                Some(path) => path.clone(),
+                None => Err(format!("Answers file not found (tried {})",
+                    paths.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ")))?
+            }
+        }
+    };
+
+    let file = fs::File::open(&path).map_err(|e| format!("Unable to open answers file {}: {}", path.display(), e))?;
+    let expected = answers::load(&mut io::BufReader::new(file)).map_err(|e| e.in_file(&path.display().to_string()))?;
+
+    // Input files are relative to the answers file
+    let base = path.parent().unwrap_or_else(|| Path::new(""));
+
+    let checks = answers::verify(selected, &expected, base);
+
+    let (mut pass, mut fail, mut missing) = (0, 0, 0);
+
+    for check in &checks {
+        println!("{}", check);
+
+        match check.verdict {
+            Verdict::Pass => pass += 1,
+            Verdict::Fail { .. } | Verdict::Error(_) => fail += 1,
+            Verdict::Missing => missing += 1
+        }
+    }
+
+    println!();
+    println!("{} passed, {} failed, {} missing", pass, fail, missing);
+
+    if fail > 0 {
+        Err(format!("{} answers did not verify", fail))?
+    }
+
+    Ok(())
+}
+
+/// Times the selected days, optionally saving the results and comparing with a baseline
+fn bench(selected: &[&Day], options: &Options) -> Result<(), Box<dyn std::error::Error>> {
+    let repeat = options.repeat.unwrap_or(DEFAULT_REPEAT);
+
+    // Load the baseline first so a bad file is reported before the long part
+    let baseline = match &options.baseline {
+        Some(file) => {
+            let f = fs::File::open(file).map_err(|e| format!("Unable to open baseline file {}: {}", file, e))?;
+            Some(bench::load_csv(&mut io::BufReader::new(f)).map_err(|e| e.in_file(file))?)
+        }
+        None => None
+    };
+
+    let mut timings = Vec::new();
+
+    for d in selected {
+        let input = Input::new(d.day, options.input.as_deref());
+
+        timings.append(&mut bench::bench(d, &input, repeat)?);
+    }
+
+    println!("{:>3} {:<6} {:>5} {:>12} {:>12} {:>12} {:>12}", "Day", "Phase", "Runs", "Min", "Median", "Mean", "Max");
+
+    for t in &timings {
+        println!("{:>3} {:<6} {:>5} {:>12} {:>12} {:>12} {:>12}", t.day, t.phase, t.runs,
+            Readable(t.min).to_string(), Readable(t.median).to_string(),
+            Readable(t.mean).to_string(), Readable(t.max).to_string());
+    }
+
+    if let Some(file) = &options.output {
+        let mut f = fs::File::create(file).map_err(|e| format!("Unable to create output file {}: {}", file, e))?;
+        bench::write_csv(&timings, &mut f)?;
+
+        println!();
+        println!("Results written to {}", file);
+    }
+
+    if let Some(baseline) = baseline {
+        let threshold = options.threshold.unwrap_or(DEFAULT_THRESHOLD);
+        let comparisons = bench::compare(&timings, &baseline, threshold);
+
+        println!();
+        println!("{:>3} {:<6} {:>12} {:>12} {:>9}", "Day", "Phase", "Baseline", "Median", "Change");
+
+        for c in &comparisons {
+            println!("{:>3} {:<6} {:>12} {:>12} {:>+8.1}%{}", c.day, c.phase,
+                Readable(c.baseline).to_string(), Readable(c.current).to_string(), c.change(),
+                if c.slower { " SLOWER" } else { "" });
+        }
+
+        let slower = comparisons.iter().filter(|c| c.slower).count();
+
+        if slower > 0 {
+            Err(format!("{} phases more than {}% slower than the baseline", slower, threshold))?
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a random input for the selected day
+fn gen(selected: &[&Day], options: &Options) -> Result<(), Box<dyn std::error::Error>> {
+    let mut settings = aoc_gen::Settings::new();
+
+    for setting in &options.settings {
+        settings.add(setting)?;
+    }
+
+    for d in selected {
+        let input = aoc_gen::generate(d.day, options.seed.unwrap_or(DEFAULT_SEED), &settings)?;
+
+        match &options.output {
+            Some(file) => fs::write(file, input).map_err(|e| format!("Unable to create output file {}: {}", file, e))?,
+            None => print!("{}", input)
+        }
+    }
+
+    Ok(())
+}
+
+/// Re-solves the day whenever its input, or one of its examples from the
+/// answers registry, changes. Runs until interrupted
+fn watch(d: &Day, options: &Options) -> Result<(), Box<dyn std::error::Error>> {
+    let input = Input::new(d.day, options.input.as_deref());
+    let input_path = input.path().ok_or("Standard input can't be watched")?;
+
+    let mut files = vec![Watched::new(input_path.clone(), (1..=d.parts).collect())];
+
+    // Examples only when watching the default input
+    if options.input.is_none() {
+        files.append(&mut watch_examples(d, &input_path)?);
+    }
+
+    println!("Watching {}", files.iter().map(|f| f.path.display().to_string()).collect::<Vec<_>>().join(", "));
+    println!();
+
+    // Panics are reported with the answers
+    panic::set_hook(Box::new(|_| ()));
+
+    loop {
+        for file in &mut files {
+            if !file.changed() {
+                continue
+            }
+
+            println!("== {} ==", file.path.display());
+
+            match file.run(d) {
+                Ok(changes) => {
+                    for (part, change) in changes {
+                        println!("Part {}: {}", part, change);
+                    }
+                }
+                Err(e) => println!("Error: {}", e)
+            }
+
+            println!();
+        }
+
+        thread::sleep(WATCH_INTERVAL);
+    }
+}
+
+/// The day's other inputs from the answers registry, if there is one, each
+/// with the parts it has answers for
+fn watch_examples(d: &Day, input_path: &Path) -> Result<Vec<Watched>, Box<dyn std::error::Error>> {
+    let path = match answers::search_paths().into_iter().find(|p| p.is_file()) {
+        Some(path) => path,
+        None => return Ok(Vec::new())
+    };
+
+    let file = fs::File::open(&path).map_err(|e| format!("Unable to open answers file {}: {}", path.display(), e))?;
+    let expected = answers::load(&mut io::BufReader::new(file)).map_err(|e| e.in_file(&path.display().to_string()))?;
+
+    let base = path.parent().unwrap_or_else(|| Path::new(""));
+    let same_file = |a: &Path, b: &Path| a == b || matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b);
+
+    // Group the parts by file, keeping registry order
+    let mut examples: Vec<(PathBuf, Vec<u8>)> = Vec::new();
+
+    for e in expected.iter().filter(|e| e.day == d.day && e.part <= d.parts) {
+        let example = base.join(&e.input);
+
+        if same_file(&example, input_path) {
+            continue
+        }
+
+        match examples.iter_mut().find(|(path, _)| *path == example) {
+            Some((_, parts)) => parts.push(e.part),
+            None => examples.push((example, vec![e.part]))
+        }
+    }
+
+    Ok(examples.into_iter().map(|(path, parts)| Watched::new(path, parts)).collect())
+}
+
+/// Serves solve requests over HTTP until killed
+fn serve(options: &Options) -> Result<(), Box<dyn std::error::Error>> {
+    let limits = serve::Limits {
+        time: Duration::from_secs_f64(options.time_limit.unwrap_or(DEFAULT_TIME_LIMIT)),
+        memory_mb: options.memory_limit.unwrap_or(DEFAULT_MEMORY_LIMIT),
+        input_bytes: MAX_INPUT_BYTES
+    };
+
+    serve::serve(&DAYS, options.port.unwrap_or(DEFAULT_PORT), options.threads.unwrap_or(DEFAULT_WORKERS), &limits)
+}
+
+fn parse_args(args: &[String]) -> Result<Options, Box<dyn std::error::Error>> {
+    let mut options: Options = Default::default();
+    let mut selector = Vec::new();
+
+    let mut arg_iter = args.iter();
+
+    while let Some(arg) = arg_iter.next() {
+        let mut value = || match arg_iter.next() {
+            Some(value) => Ok(value.clone()),
+            None => Err(USAGE)
+        };
+
+        match arg.as_str() {
+            "-i" | "--input" => options.input = Some(value()?),
+            "-j" | "--json" => options.json = true,
+            "-a" | "--answers" => options.answers = Some(value()?),
+            "-r" | "--repeat" => {
+                let repeat = value()?;
+
+                match repeat.parse::<usize>() {
+                    Ok(n) if n > 0 => options.repeat = Some(n),
+                    _ => Err(format!("Invalid repeat count '{}'", repeat))?
+                }
+            }
+            "-o" | "--output" => options.output = Some(value()?),
+            "-b" | "--baseline" => options.baseline = Some(value()?),
+            "-t" | "--threshold" => {
+                let threshold = value()?;
+
+                match threshold.parse::<f64>() {
+                    Ok(t) if t >= 0.0 => options.threshold = Some(t),
+                    _ => Err(format!("Invalid threshold '{}'", threshold))?
+                }
+            }
+            "-s" | "--seed" => {
+                let seed = value()?;
+
+                match seed.parse::<u64>() {
+                    Ok(n) => options.seed = Some(n),
+                    _ => Err(format!("Invalid seed '{}'", seed))?
+                }
+            }
+            "--set" => options.settings.push(value()?),
+            "-p" | "--parallel" => options.parallel = true,
+            "--threads" => {
+                let threads = value()?;
+
+                match threads.parse::<usize>() {
+                    Ok(n) if n > 0 => options.threads = Some(n),
+                    _ => Err(format!("Invalid thread count '{}'", threads))?
+                }
+            }
+            "--port" => {
+                let port = value()?;
+
+                match port.parse::<u16>() {
+                    Ok(n) if n > 0 => options.port = Some(n),
+                    _ => Err(format!("Invalid port '{}'", port))?
+                }
+            }
+            "--time-limit" => {
+                let limit = value()?;
+
+                match limit.parse::<f64>() {
+                    Ok(t) if t > 0.0 && t.is_finite() => options.time_limit = Some(t),
+                    _ => Err(format!("Invalid time limit '{}'", limit))?
+                }
+            }
+            "--memory-limit" => {
+                let limit = value()?;
+
+                match limit.parse::<u64>() {
+                    Ok(n) if n > 0 && n < 1 << 40 => options.memory_limit = Some(n),
+                    _ => Err(format!("Invalid memory limit '{}'", limit))?
+                }
+            }
+            _ => selector.push(arg)
+        }
+    }
+
+    match selector.first().map(|s| s.as_str()) {
+        Some("verify") => options.mode = Mode::Verify,
+        Some("bench") => options.mode = Mode::Bench,
+        Some("gen") => options.mode = Mode::Gen,
+        Some("watch") => options.mode = Mode::Watch,
+        Some("serve") => options.mode = Mode::Serve,
+        _ => {}
+    }
+
+    if options.mode != Mode::Run {
+        selector.remove(0);
+    }
+
+    match (options.mode, selector.len()) {
+        (Mode::Run, 1) if selector[0] == "all" => {},
+        (Mode::Run, 2) => {
+            options.day = Some(parse_number(selector[0], 1..=25, "day")?);
+            options.part = Some(parse_number(selector[1], 1..=2, "part")?);
+        },
+        (Mode::Verify, 0) | (Mode::Bench, 0) | (Mode::Serve, 0) => {},
+        (Mode::Serve, _) => Err(USAGE)?,
+        (Mode::Gen, 0) => Err("A day must be given to generate input for")?,
+        (Mode::Watch, 0) => Err("A day must be given to watch")?,
+        (_, 1) => options.day = Some(parse_number(selector[0], 1..=25, "day")?),
+        _ => Err(USAGE)?
+    }
+
+    if options.json && options.mode != Mode::Run {
+        Err("JSON output is only available when running days")?
+    }
+
+    if options.parallel && (options.mode != Mode::Run || options.json) {
+        Err("Parallel runs are only available when running days without JSON output")?
+    }
+
+    if options.threads.is_some() && !options.parallel && options.mode != Mode::Serve {
+        Err("A thread count can only be given with parallel or serve")?
+    }
+
+    if options.mode != Mode::Serve && (options.port.is_some() || options.time_limit.is_some() || options.memory_limit.is_some()) {
+        Err("Port, time limit and memory limit can only be given with serve")?
+    }
+
+    if options.mode == Mode::Serve && options.input.is_some() {
+        Err("Inputs are sent in requests to serve")?
+    }
+
+    if options.answers.is_some() && options.mode != Mode::Verify {
+        Err("An answers file can only be given with verify")?
+    }
+
+    if options.mode != Mode::Bench && (options.repeat.is_some() || options.baseline.is_some() || options.threshold.is_some()) {
+        Err("Repeat, baseline and threshold can only be given with bench")?
+    }
+
+    if options.output.is_some() && options.mode != Mode::Bench && options.mode != Mode::Gen {
+        Err("An output file can only be given with bench or gen")?
+    }
+
+    if options.mode != Mode::Gen && (options.seed.is_some() || !options.settings.is_empty()) {
+        Err("Seed and settings can only be given with gen")?
+    }
+
+    if options.input.is_some() {
+        if options.mode == Mode::Verify {
+            Err("Input files for verification come from the answers file")?
+        }
+
+        if options.mode == Mode::Gen {
+            Err("An input file can't be given with gen")?
+        }
+
+        if options.day.is_none() {
+            Err("An input file can only be given for a single day")?
+        }
+    }
+
+    Ok(options)
+}
+
+fn parse_number(arg: &str, range: std::ops::RangeInclusive<u8>, what: &str) -> Result<u8, Box<dyn std::error::Error>> {
+    match arg.parse::<u8>() {
+        Ok(n) if range.contains(&n) => Ok(n),
+        _ => Err(format!("Invalid {} '{}', expecting {} to {}", what, arg, range.start(), range.end()))?
+    }
+}
+
+#[test]
+fn parse_args_test() {
+    let args = |a: &[&str]| -> Vec<String> { a.iter().map(|s| s.to_string()).collect() };
+    let options = |day, part, input: Option<&str>| Options { day, part, input: input.map(|i| i.to_string()), ..Default::default() };
+    let verify = |day, answers: Option<&str>| Options { mode: Mode::Verify, day, answers: answers.map(|a| a.to_string()), ..Default::default() };
+
+    assert_eq!(options(None, None, None), parse_args(&args(&["all"])).unwrap());
+    assert_eq!(options(Some(20), None, None), parse_args(&args(&["20"])).unwrap());
+    assert_eq!(options(Some(20), Some(2), None), parse_args(&args(&["20", "2"])).unwrap());
+    assert_eq!(options(Some(20), Some(2), Some("my.txt")), parse_args(&args(&["20", "2", "--input", "my.txt"])).unwrap());
+    assert_eq!(options(Some(7), None, Some("-")), parse_args(&args(&["-i", "-", "7"])).unwrap());
+    assert!(parse_args(&args(&[])).is_err());
+    assert!(parse_args(&args(&["26"])).is_err());
+    assert!(parse_args(&args(&["1", "3"])).is_err());
+    assert!(parse_args(&args(&["one"])).is_err());
+    assert!(parse_args(&args(&["7", "--input"])).is_err());
+    assert!(parse_args(&args(&["all", "--input", "my.txt"])).is_err());
+    assert_eq!(Options { json: true, ..Default::default() }, parse_args(&args(&["all", "--json"])).unwrap());
+    assert!(parse_args(&args(&["verify", "--json"])).is_err());
+
+    assert_eq!(verify(None, None), parse_args(&args(&["verify"])).unwrap());
+    assert_eq!(verify(Some(7), Some("my.txt")), parse_args(&args(&["verify", "7", "--answers", "my.txt"])).unwrap());
+    assert!(parse_args(&args(&["verify", "7", "1"])).is_err());
+    assert!(parse_args(&args(&["verify", "--input", "my.txt"])).is_err());
+    assert!(parse_args(&args(&["7", "--answers", "my.txt"])).is_err());
+
+    assert_eq!(Options { mode: Mode::Bench, ..Default::default() }, parse_args(&args(&["bench"])).unwrap());
+    assert_eq!(Options {
+        mode: Mode::Bench,
+        day: Some(15),
+        repeat: Some(3),
+        output: Some("new.csv".to_string()),
+        baseline: Some("old.csv".to_string()),
+        threshold: Some(5.0),
+        ..Default::default()
+    }, parse_args(&args(&["bench", "15", "-r", "3", "-o", "new.csv", "-b", "old.csv", "-t", "5"])).unwrap());
+    assert!(parse_args(&args(&["bench", "15", "1"])).is_err());
+    assert!(parse_args(&args(&["bench", "--repeat", "0"])).is_err());
+    assert!(parse_args(&args(&["15", "--repeat", "3"])).is_err());
+
+    assert_eq!(Options {
+        mode: Mode::Gen,
+        day: Some(4),
+        seed: Some(42),
+        settings: vec!["passports=10".to_string(), "invalid=0.5".to_string()],
+        output: Some("gen04.txt".to_string()),
+        ..Default::default()
+    }, parse_args(&args(&["gen", "4", "--seed", "42", "--set", "passports=10", "--set", "invalid=0.5", "-o", "gen04.txt"])).unwrap());
+    assert!(parse_args(&args(&["gen"])).is_err());
+    assert!(parse_args(&args(&["gen", "4", "1"])).is_err());
+    assert!(parse_args(&args(&["gen", "4", "--seed", "x"])).is_err());
+    assert!(parse_args(&args(&["4", "--seed", "1"])).is_err());
+    assert!(parse_args(&args(&["verify", "--output", "out.txt"])).is_err());
+
+    assert_eq!(Options { parallel: true, threads: Some(4), ..Default::default() }, parse_args(&args(&["all", "-p", "--threads", "4"])).unwrap());
+    assert_eq!(Options { day: Some(15), parallel: true, ..Default::default() }, parse_args(&args(&["15", "--parallel"])).unwrap());
+    assert!(parse_args(&args(&["all", "--parallel", "--json"])).is_err());
+    assert!(parse_args(&args(&["bench", "--parallel"])).is_err());
+    assert!(parse_args(&args(&["all", "--threads", "4"])).is_err());
+    assert!(parse_args(&args(&["all", "-p", "--threads", "0"])).is_err());
+
+    assert_eq!(Options { mode: Mode::Watch, day: Some(10), ..Default::default() }, parse_args(&args(&["watch", "10"])).unwrap());
+    assert_eq!(Options { mode: Mode::Watch, day: Some(10), input: Some("my.txt".to_string()), ..Default::default() },
+        parse_args(&args(&["watch", "10", "-i", "my.txt"])).unwrap());
+    assert!(parse_args(&args(&["watch"])).is_err());
+    assert!(parse_args(&args(&["watch", "10", "1"])).is_err());
+    assert!(parse_args(&args(&["watch", "10", "--json"])).is_err());
+
+    assert_eq!(Options { mode: Mode::Serve, ..Default::default() }, parse_args(&args(&["serve"])).unwrap());
+    assert_eq!(Options { mode: Mode::Serve, port: Some(8080), threads: Some(2), time_limit: Some(0.5), memory_limit: Some(256), ..Default::default() },
+        parse_args(&args(&["serve", "--port", "8080", "--threads", "2", "--time-limit", "0.5", "--memory-limit", "256"])).unwrap());
+    assert!(parse_args(&args(&["serve", "15"])).is_err());
+    assert!(parse_args(&args(&["serve", "--time-limit", "0"])).is_err());
+    assert!(parse_args(&args(&["serve", "--port", "70000"])).is_err());
+    assert!(parse_args(&args(&["serve", "-i", "input15.txt"])).is_err());
+    assert!(parse_args(&args(&["15", "1", "--port", "8080"])).is_err());
+}
+
+#[test]
+fn gen_test() {
+    diag::set_quiet(true);
+
+    // Generated inputs should load and solve. Part 2 of days 15 and 23 is too slow for an unoptimised build
+    for d in &DAYS {
+        let parts: Vec<u8> = (1..=d.parts).filter(|&p| p == 1 || (d.day != 15 && d.day != 23)).collect();
+
+        let mut settings = aoc_gen::Settings::new();
+
+        // Settling the full size seat layout is slow unoptimised
+        if d.day == 11 {
+            settings.add("width=30").unwrap();
+            settings.add("height=30").unwrap();
+        }
+
+        for seed in 1..=3 {
+            let input = aoc_gen::generate(d.day, seed, &settings).unwrap();
+
+            if let Err(e) = d.run(&mut input.as_bytes(), &parts) {
+                panic!("Day {} seed {}: {}", d.day, seed, e)
+            }
+        }
+    }
+}
+
+#[test]
+fn fuzz_corpus_test() {
+    diag::set_quiet(true);
+
+    // Everything in the fuzzing corpus must parse or give an error without panicking
+    for d in &DAYS {
+        let dir = format!("../fuzz/corpus/day{:02}", d.day);
+
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            let data = fs::read(&path).unwrap();
+
+            let _ = (d.parse)(&mut &data[..]);
+        }
+    }
+}
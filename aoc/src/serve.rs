@@ -0,0 +1,215 @@
+use std::env;
+use std::error::Error;
+use std::io::{self, Read, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use aoc_common::bench::Readable;
+use aoc_common::{Day, Json};
+
+/// Limits on each request. Solving runs in a child process so a slow or
+/// greedy day can't hold up the others
+pub struct Limits {
+    pub time: Duration,
+    pub memory_mb: u64,
+    pub input_bytes: usize
+}
+
+/// An HTTP status and JSON body
+type Reply = (u16, String);
+
+const ROUTE: &str = "POST /solve/<day>/<part>";
+
+/// Serves requests on the local port with a number of worker threads until killed
+pub fn serve(days: &[Day], port: u16, workers: usize, limits: &Limits) -> Result<(), Box<dyn Error>> {
+    let server = Server::http(("127.0.0.1", port)).map_err(|e| format!("Unable to listen on port {}: {}", port, e))?;
+
+    println!("Listening on http://127.0.0.1:{}, {} with the input as the body", port, ROUTE);
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while let Ok(request) = server.recv() {
+                    handle(request, days, limits);
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
+
+fn handle(mut request: Request, days: &[Day], limits: &Limits) {
+    let start = Instant::now();
+
+    let (status, body) = match route(request.method(), request.url(), days) {
+        Ok((day, part)) => match read_body(&mut request, limits.input_bytes) {
+            Ok(input) => solve(day, part, &input, limits),
+            Err(reply) => reply
+        },
+        Err(reply) => reply
+    };
+
+    println!("{} {} {} {}", request.method(), request.url(), status, Readable(start.elapsed()));
+
+    let response = Response::from_string(body)
+        .with_status_code(status)
+        .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+
+    if let Err(e) = request.respond(response) {
+        eprintln!("Unable to send response: {}", e);
+    }
+}
+
+fn error(status: u16, message: &str) -> Reply {
+    (status, Json::object(vec![("error", Json::str(message))]).to_string())
+}
+
+/// Works out the day and part from the request
+fn route(method: &Method, url: &str, days: &[Day]) -> Result<(u8, u8), Reply> {
+    let path = url.split('?').next().unwrap_or("");
+
+    let (day, part) = match path.strip_prefix("/solve/").map(|rest| rest.split('/').collect::<Vec<_>>()).as_deref() {
+        Some([day, part]) => (*day, *part),
+        _ => Err(error(404, &format!("Not found, expecting {}", ROUTE)))?
+    };
+
+    if *method != Method::Post {
+        Err(error(405, &format!("Method not allowed, expecting {}", ROUTE)))?
+    }
+
+    let d = match day.parse::<u8>().ok().and_then(|n| days.iter().find(|d| d.day == n)) {
+        Some(d) => d,
+        None => Err(error(404, &format!("No day '{}'", day)))?
+    };
+
+    match part.parse::<u8>() {
+        Ok(part) if part >= 1 && part <= d.parts => Ok((d.day, part)),
+        _ => Err(error(404, &format!("Day {} has no part '{}'", d.day, part)))
+    }
+}
+
+fn read_body(request: &mut Request, limit: usize) -> Result<Vec<u8>, Reply> {
+    let mut input = Vec::new();
+
+    request.as_reader().take(limit as u64 + 1).read_to_end(&mut input)
+        .map_err(|e| error(400, &format!("Unable to read the input: {}", e)))?;
+
+    if input.len() > limit {
+        Err(error(413, &format!("Input is over the limit of {} bytes", limit)))?
+    }
+
+    Ok(input)
+}
+
+/// Solves the part in a child process running 'aoc <day> <part> --input - --json',
+/// replying with its JSON output. The child is killed if it runs out of time
+fn solve(day: u8, part: u8, input: &[u8], limits: &Limits) -> Reply {
+    match run_child(day, part, input, limits) {
+        Ok(reply) => reply,
+        Err(e) => error(500, &format!("Unable to run the solver: {}", e))
+    }
+}
+
+fn run_child(day: u8, part: u8, input: &[u8], limits: &Limits) -> Result<Reply, Box<dyn Error>> {
+    let mut command = Command::new(env::current_exe()?);
+
+    command.args(&[day.to_string(), part.to_string(), "--input".to_string(), "-".to_string(), "--json".to_string()])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null());
+
+    limit_memory(&mut command, limits.memory_mb * 1024 * 1024);
+
+    let mut child = command.spawn()?;
+
+    // Feed the input and collect the output on their own threads so neither pipe can fill up
+    let mut stdin = child.stdin.take().ok_or("No child stdin")?;
+    let input = input.to_vec();
+    let writer = thread::spawn(move || stdin.write_all(&input));
+
+    let mut stdout = child.stdout.take().ok_or("No child stdout")?;
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let deadline = Instant::now() + limits.time;
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status)
+        }
+
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            break None
+        }
+
+        thread::sleep(Duration::from_millis(5));
+    };
+
+    // The child may exit without reading all of the input
+    let _ = writer.join();
+    let output = reader.join().map_err(|_| "Output reader panicked")??;
+
+    let reply = match status {
+        None => error(503, &format!("Time limit of {} exceeded", Readable(limits.time))),
+        Some(status) if status.success() => (200, output.trim_end().to_string()),
+        // Parse errors and errors from the solution still give a JSON document
+        Some(status) if status.code() == Some(1) && output.starts_with('{') => (422, output.trim_end().to_string()),
+        Some(_) => error(500, &format!("Solver crashed, possibly by exceeding the memory limit of {}MB", limits.memory_mb))
+    };
+
+    Ok(reply)
+}
+
+/// Limits the child's address space
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, bytes: u64) {
+    use std::os::unix::process::CommandExt;
+
+    let limit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t
+    };
+
+    // Runs in the child between fork and exec, so only calls setrlimit
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                Err(io::Error::last_os_error())?
+            }
+
+            Ok(())
+        });
+    }
+}
+
+/// Memory limits aren't supported on this platform
+#[cfg(not(unix))]
+fn limit_memory(_: &mut Command, _: u64) {
+}
+
+#[test]
+fn route_test() {
+    let days = [Day::of::<day01::Day01>(), Day::of::<day25::Day25>()];
+
+    assert_eq!(Ok((1, 2)), route(&Method::Post, "/solve/1/2", &days));
+    assert_eq!(Ok((25, 1)), route(&Method::Post, "/solve/25/1?verbose", &days));
+
+    let status = |method, url| route(&method, url, &days).unwrap_err().0;
+
+    assert_eq!(405, status(Method::Get, "/solve/1/2"));
+    assert_eq!(404, status(Method::Post, "/"));
+    assert_eq!(404, status(Method::Post, "/solve/1"));
+    assert_eq!(404, status(Method::Post, "/solve/1/2/3"));
+    assert_eq!(404, status(Method::Post, "/solve/2/1"));
+    assert_eq!(404, status(Method::Post, "/solve/25/2"));
+    assert_eq!(404, status(Method::Post, "/solve/x/1"));
+    assert_eq!("{\"error\":\"Day 25 has no part '2'\"}", route(&Method::Post, "/solve/25/2", &days).unwrap_err().1);
+}
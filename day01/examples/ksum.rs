@@ -0,0 +1,52 @@
+use std::env;
+use std::error::Error;
+use std::process;
+
+use aoc_common::{Input, Solution};
+use day01::{Day01, Parsing};
+
+/// Lists every combination of k expenses adding up to the target, e.g.
+/// cargo run --example ksum -- 4 2020 [<input file>] [--lenient]
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    // Skip invalid lines with a warning rather than failing
+    let parsing = match args.iter().position(|a| a == "--lenient") {
+        Some(pos) => {
+            args.remove(pos);
+            Parsing::Lenient
+        }
+        None => Parsing::Strict
+    };
+
+    let (k, target) = match args.as_slice() {
+        [k, target] | [k, target, _] => (k, target),
+        _ => Err("Usage: ksum <k> <target> [<input file>|-] [--lenient]")?
+    };
+
+    let k = k.parse::<usize>().map_err(|_| format!("Invalid k '{}'", k))?;
+    let target = target.parse::<i64>().map_err(|_| format!("Invalid target '{}'", target))?;
+
+    let (name, mut reader) = Input::new(Day01::DAY, args.get(2).map(|s| s.as_str())).open()?;
+    let numbers = day01::load_numbers(&mut reader, parsing).map_err(|e| e.in_file(&name))?;
+
+    let combinations = day01::find_sums(&numbers, k, target);
+
+    for c in &combinations {
+        match c.product() {
+            Some(product) => println!("{} = {}, product {}", c, target, product),
+            None => println!("{} = {}, product overflows", c, target)
+        }
+    }
+
+    println!("Combinations: {}", combinations.len());
+
+    Ok(())
+}
@@ -0,0 +1,314 @@
+use std::fmt;
+use std::io::BufRead;
+
+use aoc_common::{debug, parse, stat, warn, ParseError, Solution};
+
+pub struct Day01;
+
+/// Target sum for both parts
+const SUM: i64 = 2020;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Model = Vec<Entry>;
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_numbers(input, Parsing::Strict)
+    }
+
+    fn part1(numbers: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        solve(numbers, 2, SUM)
+    }
+
+    fn part2(numbers: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        solve(numbers, 3, SUM)
+    }
+}
+
+/// An expense report entry
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Entry {
+    /// 1-based line number
+    pub line: usize,
+    pub amount: i64
+}
+
+/// Entries which add up to the target, in line order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Combination {
+    pub entries: Vec<Entry>
+}
+
+impl Combination {
+    /// Product of the amounts, None if it overflows
+    pub fn product(&self) -> Option<i128> {
+        self.entries.iter().try_fold(1i128, |product, e| product.checked_mul(e.amount as i128))
+    }
+}
+
+impl fmt::Display for Combination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, e) in self.entries.iter().enumerate() {
+            if i > 0 {
+                write!(f, " + ")?;
+            }
+
+            write!(f, "{} on line {}", e.amount, e.line)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Answers with the product of the first k entries summing to the target,
+/// warning if there is more than one combination
+fn solve(entries: &[Entry], k: usize, target: i64) -> Result<i128, Box<dyn std::error::Error>> {
+    let combinations = find_sums(entries, k, target);
+
+    for c in &combinations {
+        debug!("{} = {}", c, target);
+    }
+
+    stat("solutions", combinations.len());
+
+    let first = match combinations.first() {
+        Some(c) => c,
+        None => Err(format!("Sum to {} not found", target))?
+    };
+
+    if combinations.len() > 1 {
+        warn(format!("{} combinations sum to {}, using the first", combinations.len(), target));
+    }
+
+    for (e, name) in first.entries.iter().zip(["line_a", "line_b", "line_c"].iter()) {
+        stat(name, e.line);
+    }
+
+    match first.product() {
+        Some(product) => Ok(product),
+        None => Err(format!("Product of {} overflows", first))?
+    }
+}
+
+/// Finds every distinct set of k entries (by line) which sums to the target,
+/// ordered by line numbers. Pairs are found in O(n) after sorting, k entries
+/// in O(n^(k-1)). Amounts may be negative and are summed without overflow
+pub fn find_sums(entries: &[Entry], k: usize, target: i64) -> Vec<Combination> {
+    let mut sorted = entries.to_vec();
+    sorted.sort_by_key(|e| e.amount);
+
+    let mut found = Vec::new();
+
+    if k > 0 {
+        search(&sorted, k, target as i128, &mut Vec::new(), &mut found);
+    }
+
+    for c in &mut found {
+        c.entries.sort_by_key(|e| e.line);
+    }
+
+    found.sort_by(|a, b| a.entries.iter().map(|e| e.line).cmp(b.entries.iter().map(|e| e.line)));
+
+    found
+}
+
+/// Adds the combinations of k entries from the sorted slice summing to the
+/// target to those already chosen
+fn search(sorted: &[Entry], k: usize, target: i128, chosen: &mut Vec<Entry>, found: &mut Vec<Combination>) {
+    let mut add = |entries: &[Entry]| {
+        found.push(Combination { entries: chosen.iter().chain(entries.iter()).copied().collect() });
+    };
+
+    match k {
+        1 => {
+            let start = sorted.partition_point(|e| (e.amount as i128) < target);
+
+            for e in sorted[start..].iter().take_while(|e| e.amount as i128 == target) {
+                add(&[*e]);
+            }
+        }
+        2 => {
+            if sorted.is_empty() {
+                return
+            }
+
+            let (mut lo, mut hi) = (0, sorted.len() - 1);
+
+            while lo < hi {
+                let (a, b) = (sorted[lo].amount, sorted[hi].amount);
+
+                match (a as i128 + b as i128).cmp(&target) {
+                    std::cmp::Ordering::Less => lo += 1,
+                    std::cmp::Ordering::Greater => hi -= 1,
+                    std::cmp::Ordering::Equal if a == b => {
+                        // Every pair in between has the same amounts
+                        for i in lo..=hi {
+                            for j in (i + 1)..=hi {
+                                add(&[sorted[i], sorted[j]]);
+                            }
+                        }
+
+                        break
+                    }
+                    std::cmp::Ordering::Equal => {
+                        // Pair each entry in the run of a with each in the run of b
+                        let lo_end = lo + sorted[lo..].iter().take_while(|e| e.amount == a).count();
+                        let hi_start = hi + 1 - sorted[..=hi].iter().rev().take_while(|e| e.amount == b).count();
+
+                        for i in lo..lo_end {
+                            for j in hi_start..=hi {
+                                add(&[sorted[i], sorted[j]]);
+                            }
+                        }
+
+                        lo = lo_end;
+                        hi = hi_start - 1;
+                    }
+                }
+            }
+        }
+        _ => {
+            for (i, e) in sorted.iter().enumerate() {
+                chosen.push(*e);
+                search(&sorted[i + 1..], k - 1, target - e.amount as i128, chosen, found);
+                chosen.pop();
+            }
+        }
+    }
+}
+
+/// How lines which aren't valid amounts are handled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Parsing {
+    /// Fail, giving every invalid line
+    Strict,
+    /// Skip them with a warning saying how many and why
+    Lenient
+}
+
+/// Most invalid lines described in a lenient warning
+const MAX_REASONS: usize = 5;
+
+/// Loads the expense report, one amount per line. Blank lines are ignored
+pub fn load_numbers(input: &mut dyn BufRead, parsing: Parsing) -> Result<Vec<Entry>, ParseError> {
+    let mut entries = Vec::new();
+    let mut invalid = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+        let text = line.text.trim();
+
+        if text.is_empty() {
+            continue
+        }
+
+        match line.parse(text) {
+            Ok(amount) => entries.push(Entry { line: line.number, amount }),
+            Err(e) => invalid.push(e)
+        }
+    }
+
+    if invalid.is_empty() {
+        return Ok(entries)
+    }
+
+    match parsing {
+        Parsing::Strict => {
+            let first = invalid.remove(0);
+            let others = invalid.iter().map(|e| e.line).collect();
+
+            Err(first.also_invalid(others))
+        }
+        Parsing::Lenient => {
+            let mut reasons: Vec<String> = invalid.iter().take(MAX_REASONS).map(|e| e.to_string()).collect();
+
+            if invalid.len() > MAX_REASONS {
+                reasons.push(format!("{} more", invalid.len() - MAX_REASONS));
+            }
+
+            stat("skipped", invalid.len());
+            warn(format!("Skipped {} invalid line{}: {}", invalid.len(), if invalid.len() == 1 { "" } else { "s" }, reasons.join("; ")));
+
+            Ok(entries)
+        }
+    }
+}
+
+#[test]
+fn test_example() {
+    let model = Day01::parse(&mut include_str!("../../example01.txt").as_bytes()).unwrap();
+
+    assert_eq!(514579, Day01::part1(&model).unwrap());
+    assert_eq!(241861950, Day01::part2(&model).unwrap());
+}
+
+#[test]
+fn find_sums_test() {
+    let entries = |amounts: &[i64]| amounts.iter().enumerate().map(|(i, &amount)| Entry { line: i + 1, amount }).collect::<Vec<_>>();
+    let lines = |c: &Combination| c.entries.iter().map(|e| e.line).collect::<Vec<_>>();
+
+    // Every pair of equal amounts is a distinct solution
+    let found = find_sums(&entries(&[1010, 5, 1010, 2015, 1010]), 2, 2020);
+    assert_eq!(vec![vec![1, 3], vec![1, 5], vec![2, 4], vec![3, 5]], found.iter().map(lines).collect::<Vec<_>>());
+    assert_eq!("5 on line 2 + 2015 on line 4", found[2].to_string());
+
+    // Compare with checking every combination of four
+    let numbers = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7];
+    let n = numbers.len();
+    let mut expected = Vec::new();
+
+    for a in 0..n {
+        for b in (a + 1)..n {
+            for c in (b + 1)..n {
+                for d in (c + 1)..n {
+                    if numbers[a] + numbers[b] + numbers[c] + numbers[d] == 20 {
+                        expected.push(vec![a + 1, b + 1, c + 1, d + 1]);
+                    }
+                }
+            }
+        }
+    }
+
+    assert_eq!(expected, find_sums(&entries(&numbers), 4, 20).iter().map(lines).collect::<Vec<_>>());
+    assert_eq!(vec![vec![6], vec![13]], find_sums(&entries(&numbers), 1, 9).iter().map(lines).collect::<Vec<_>>());
+    assert!(find_sums(&entries(&numbers), 0, 0).is_empty());
+    assert!(find_sums(&[], 2, 0).is_empty());
+
+    let negative = find_sums(&entries(&[-5, 2025, 7, -12, 0]), 3, 2020);
+    assert_eq!(vec![vec![1, 2, 5], vec![2, 3, 4]], negative.iter().map(lines).collect::<Vec<_>>());
+    assert_eq!(Some(0), negative[0].product());
+
+    // Sums and products of large amounts don't overflow
+    let big = find_sums(&entries(&[i64::MAX, i64::MIN, i64::MAX, i64::MIN]), 4, -2);
+    assert_eq!(1, big.len());
+    assert_eq!(None, big[0].product(), "Product overflows");
+    assert_eq!(Some(-(i64::MAX as i128 * i64::MAX as i128)), find_sums(&entries(&[i64::MAX, -i64::MAX]), 2, 0)[0].product());
+}
+
+#[test]
+fn load_numbers_test() {
+    let input = "1721\n\n-979\n 36 \n12x\n9223372036854775807\n3 4\n";
+
+    match load_numbers(&mut input.as_bytes(), Parsing::Strict) {
+        Err(e) => {
+            assert_eq!("line 5 column 1: invalid number '12x': invalid digit found in string (line 7 is also invalid)", e.to_string());
+            assert_eq!(vec![7], e.also_invalid);
+        }
+        Ok(_) => panic!("Invalid lines should fail")
+    }
+
+    aoc_common::diag::take();
+
+    let amounts = |entries: Vec<Entry>| entries.iter().map(|e| (e.line, e.amount)).collect::<Vec<_>>();
+
+    assert_eq!(vec![(1, 1721), (3, -979), (4, 36), (6, i64::MAX)], amounts(load_numbers(&mut input.as_bytes(), Parsing::Lenient).unwrap()));
+
+    let report = aoc_common::diag::take();
+    assert_eq!(vec!["Skipped 2 invalid lines: line 5 column 1: invalid number '12x': invalid digit found in string; \
+        line 7 column 1: invalid number '3 4': invalid digit found in string".to_string()], report.warnings);
+
+    assert_eq!(vec![(1, 5), (2, 6)], amounts(load_numbers(&mut "5\n6".as_bytes(), Parsing::Strict).unwrap()));
+}
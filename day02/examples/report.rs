@@ -0,0 +1,41 @@
+use std::env;
+use std::error::Error;
+use std::process;
+
+use aoc_common::{Input, Solution};
+use day02::{Day02, Verdict};
+
+/// Checks every password against a policy, printing a verdict for each line, e.g.
+/// cargo run --example report -- regex='^[a-z]+$' [<input file>]
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let spec = match args.as_slice() {
+        [spec] | [spec, _] => spec,
+        _ => Err(format!("Usage: report <policy> [<input file>|-], policies are {}", day02::POLICIES))?
+    };
+
+    let policy = day02::policy(spec)?;
+
+    let (name, mut reader) = Input::new(Day02::DAY, args.get(1).map(|s| s.as_str())).open()?;
+    let passents = Day02::parse(&mut reader).map_err(|e| e.in_file(&name))?;
+
+    let verdicts = day02::verdicts(&passents, policy.as_ref());
+
+    for (ent, verdict) in &verdicts {
+        println!("{}:{}: {}: {}", name, ent.line, ent, verdict);
+    }
+
+    let valid = verdicts.iter().filter(|(_, verdict)| *verdict == Verdict::Valid).count();
+
+    println!("Valid: {} of {}", valid, verdicts.len());
+
+    Ok(())
+}
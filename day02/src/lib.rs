@@ -0,0 +1,313 @@
+use std::fmt;
+use std::io::BufRead;
+
+use regex::Regex;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+use aoc_common::{parse, ParseError, Solution};
+
+pub struct Day02;
+
+/// A password database line: the policy numbers and character, then the password
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassEnt {
+    /// 1-based line number
+    pub line: usize,
+    pub n1: usize,
+    pub n2: usize,
+    /// Policy character, a single grapheme which may be several code points.
+    /// The policy character and password are normalized to NFC when loaded
+    pub pchar: String,
+    pub password: String
+}
+
+impl fmt::Display for PassEnt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{} {}: {}", self.n1, self.n2, self.pchar, self.password)
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Model = Vec<PassEnt>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_passwd(input)
+    }
+
+    fn part1(passents: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        Ok(valid_count(passents, &CountRange))
+    }
+
+    fn part2(passents: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        Ok(valid_count(passents, &ExactlyOnePosition))
+    }
+}
+
+/// Whether a password meets a policy
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Valid,
+    Invalid(String)
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Valid => write!(f, "valid"),
+            Verdict::Invalid(reason) => write!(f, "INVALID, {}", reason)
+        }
+    }
+}
+
+/// A rule passwords are checked against
+pub trait PasswordPolicy {
+    fn check(&self, ent: &PassEnt) -> Verdict;
+}
+
+/// The numbers are the minimum and maximum occurrences of the character (part 1)
+pub struct CountRange;
+
+impl PasswordPolicy for CountRange {
+    fn check(&self, ent: &PassEnt) -> Verdict {
+        let pchars = ent.password.graphemes(true).filter(|g| *g == ent.pchar).count();
+
+        if pchars >= ent.n1 && pchars <= ent.n2 {
+            Verdict::Valid
+        } else {
+            Verdict::Invalid(format!("'{}' appears {} times, expecting {} to {}", ent.pchar, pchars, ent.n1, ent.n2))
+        }
+    }
+}
+
+/// The numbers are 1-based positions, exactly one of which must hold the character (part 2).
+/// Positions count graphemes, so a letter with combining accents is one position
+pub struct ExactlyOnePosition;
+
+impl PasswordPolicy for ExactlyOnePosition {
+    fn check(&self, ent: &PassEnt) -> Verdict {
+        let password: Vec<&str> = ent.password.graphemes(true).collect();
+
+        let at = |pos: usize| match pos.checked_sub(1).and_then(|i| password.get(i)) {
+            Some(g) => Ok(*g == ent.pchar),
+            None => Err(Verdict::Invalid(format!("position {} is out of range, the password has {} characters", pos, password.len())))
+        };
+
+        let (at1, at2) = match (at(ent.n1), at(ent.n2)) {
+            (Ok(at1), Ok(at2)) => (at1, at2),
+            (Err(verdict), _) | (_, Err(verdict)) => return verdict
+        };
+
+        match (at1, at2) {
+            (true, false) | (false, true) => Verdict::Valid,
+            (true, true) => Verdict::Invalid(format!("'{}' is at both positions {} and {}", ent.pchar, ent.n1, ent.n2)),
+            (false, false) => Verdict::Invalid(format!("'{}' is at neither position {} nor {}", ent.pchar, ent.n1, ent.n2))
+        }
+    }
+}
+
+/// The password must match a regular expression
+pub struct RegexMatch(pub Regex);
+
+impl PasswordPolicy for RegexMatch {
+    fn check(&self, ent: &PassEnt) -> Verdict {
+        if self.0.is_match(&ent.password) {
+            Verdict::Valid
+        } else {
+            Verdict::Invalid(format!("doesn't match /{}/", self.0))
+        }
+    }
+}
+
+/// The password must have characters from at least this many of lower case,
+/// upper case, digits and others
+pub struct MinClasses(pub usize);
+
+impl PasswordPolicy for MinClasses {
+    fn check(&self, ent: &PassEnt) -> Verdict {
+        let classes: [fn(char) -> bool; 4] = [
+            |c| c.is_lowercase(),
+            |c| c.is_uppercase(),
+            |c| c.is_ascii_digit(),
+            |c| !c.is_lowercase() && !c.is_uppercase() && !c.is_ascii_digit()
+        ];
+
+        let found = classes.iter().filter(|class| ent.password.chars().any(*class)).count();
+
+        if found >= self.0 {
+            Verdict::Valid
+        } else {
+            Verdict::Invalid(format!("has {} character classes, expecting at least {}", found, self.0))
+        }
+    }
+}
+
+/// The password must not contain any of these
+pub struct Forbidden(pub Vec<String>);
+
+impl PasswordPolicy for Forbidden {
+    fn check(&self, ent: &PassEnt) -> Verdict {
+        match self.0.iter().find(|s| ent.password.contains(s.as_str())) {
+            Some(s) => Verdict::Invalid(format!("contains '{}'", s)),
+            None => Verdict::Valid
+        }
+    }
+}
+
+/// Policy names accepted by `policy`
+pub const POLICIES: &str = "count, position, regex=<pattern>, classes=<n> or forbid=<text>[,<text>...]";
+
+/// Builds a policy from its name, with any setting after an '='
+pub fn policy(spec: &str) -> Result<Box<dyn PasswordPolicy>, String> {
+    let (name, value) = match spec.find('=') {
+        Some(pos) => (&spec[..pos], Some(&spec[pos + 1..])),
+        None => (spec, None)
+    };
+
+    match (name, value) {
+        ("count", None) => Ok(Box::new(CountRange)),
+        ("position", None) => Ok(Box::new(ExactlyOnePosition)),
+        ("regex", Some(pattern)) => {
+            let re = Regex::new(pattern).map_err(|e| format!("Invalid regex '{}': {}", pattern, e))?;
+
+            Ok(Box::new(RegexMatch(re)))
+        }
+        ("classes", Some(n)) => match n.parse::<usize>() {
+            Ok(n) if n <= 4 => Ok(Box::new(MinClasses(n))),
+            _ => Err(format!("Invalid class count '{}', expecting 0 to 4", n))
+        },
+        ("forbid", Some(list)) => {
+            if list.split(',').any(|s| s.is_empty()) {
+                Err(format!("Invalid forbidden list '{}', expecting <text>[,<text>...]", list))?
+            }
+
+            Ok(Box::new(Forbidden(list.split(',').map(|s| s.to_string()).collect())))
+        }
+        _ => Err(format!("Unknown policy '{}', expecting {}", spec, POLICIES))
+    }
+}
+
+/// Checks every entry against the policy
+pub fn verdicts<'a>(passents: &'a [PassEnt], policy: &dyn PasswordPolicy) -> Vec<(&'a PassEnt, Verdict)> {
+    passents.iter().map(|ent| (ent, policy.check(ent))).collect()
+}
+
+fn load_passwd(input: &mut dyn BufRead) -> Result<Vec<PassEnt>, ParseError> {
+    let mut vec = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        // Get string elements
+        let mut elems = line.text.split_whitespace();
+
+        let nums_str = line.next(&mut elems, "number range")?;
+        let pchar_str = line.next(&mut elems, "policy character")?;
+        let password = line.next(&mut elems, "password")?;
+
+        if let Some(extra) = elems.next() {
+            Err(line.unexpected(extra, "end of line"))?
+        }
+
+        // Get number elements
+        let mut nums = nums_str.split("-");
+
+        let n1 = line.parse(line.next(&mut nums, "first number")?)?;
+        let n2 = line.parse(line.next(&mut nums, "second number")?)?;
+
+        // Get policy character, followed by a colon
+        // Compare normalized, so precomposed and combining accents match
+        let pchar_nfc: String = pchar_str.strip_suffix(':').unwrap_or("").nfc().collect();
+        let mut pchars = pchar_nfc.graphemes(true);
+
+        let pchar = match (pchars.next(), pchars.next()) {
+            (Some(g), None) => g.to_string(),
+            _ => Err(line.unexpected(pchar_str, "character followed by ':'"))?
+        };
+
+        // Build entry
+        let ent = PassEnt {
+            line: line.number,
+            n1,
+            n2,
+            pchar,
+            password: password.nfc().collect()
+        };
+
+        // Add to collection
+        vec.push(ent)
+    }
+
+    Ok(vec)
+}
+
+fn valid_count(passents: &[PassEnt], policy: &dyn PasswordPolicy) -> usize {
+    passents.iter().filter(|ent| policy.check(ent) == Verdict::Valid).count()
+}
+
+#[test]
+fn test_example() {
+    let model = Day02::parse(&mut include_str!("../../example02.txt").as_bytes()).unwrap();
+
+    assert_eq!(2, Day02::part1(&model).unwrap());
+    assert_eq!(1, Day02::part2(&model).unwrap());
+}
+
+#[test]
+fn policy_test() {
+    let model = Day02::parse(&mut "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc\n1-2 x: Pa55-word\n".as_bytes()).unwrap();
+
+    let check = |spec: &str| {
+        let policy = policy(spec).unwrap();
+
+        verdicts(&model, policy.as_ref()).into_iter().map(|(ent, verdict)| format!("{} {}", ent.line, verdict)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(vec!["1 valid", "2 INVALID, 'b' appears 0 times, expecting 1 to 3", "3 valid", "4 INVALID, 'x' appears 0 times, expecting 1 to 2"],
+        check("count"));
+    assert_eq!(vec!["1 valid", "2 INVALID, 'b' is at neither position 1 nor 3", "3 INVALID, 'c' is at both positions 2 and 9", "4 INVALID, 'x' is at neither position 1 nor 2"],
+        check("position"));
+    assert_eq!(vec!["1 valid", "2 valid", "3 valid", "4 INVALID, doesn't match /^[a-z]+$/"], check("regex=^[a-z]+$"));
+    assert_eq!(vec!["1 INVALID, has 1 character classes, expecting at least 3", "2 INVALID, has 1 character classes, expecting at least 3",
+        "3 INVALID, has 1 character classes, expecting at least 3", "4 valid"], check("classes=3"));
+    assert_eq!(vec!["1 INVALID, contains 'bcd'", "2 valid", "3 INVALID, contains 'ccc'", "4 INVALID, contains 'word'"], check("forbid=word,bcd,ccc"));
+
+    assert_eq!("1-2 x: Pa55-word", model[3].to_string());
+
+    // Counts don't wrap at 16 bits
+    let many = vec![model[0].clone(); 70_000];
+    assert_eq!(70_000, valid_count(&many, &CountRange));
+
+    for bad in &["counts", "count=1", "regex=(", "classes=5", "forbid=", "forbid=zz,,qq", "forbid=zz,", "regex"] {
+        assert!(policy(bad).is_err(), "{}", bad);
+    }
+}
+
+#[test]
+fn position_test() {
+    let check = |line: &str| {
+        let model = Day02::parse(&mut line.as_bytes()).unwrap();
+
+        ExactlyOnePosition.check(&model[0]).to_string()
+    };
+
+    assert_eq!("INVALID, position 0 is out of range, the password has 5 characters", check("0-3 a: abcde"));
+    assert_eq!("INVALID, position 6 is out of range, the password has 5 characters", check("1-6 a: abcde"));
+
+    // An e followed by a combining acute accent is one position, and matches a precomposed \u{e9}
+    assert_eq!("INVALID, '\u{e9}' is at both positions 2 and 3", check("2-3 \u{e9}: ce\u{301}\u{e9}s"));
+    assert_eq!("INVALID, '\u{e9}' is at both positions 2 and 3", check("2-3 e\u{301}: ce\u{301}\u{e9}s"));
+    assert_eq!("valid", check("3-4 e\u{301}: ce\u{301}\u{e9}s"));
+    assert_eq!("INVALID, '\u{e9}\u{302}' is at neither position 1 nor 2", check("1-2 e\u{301}\u{302}: \u{e9}e"));
+    assert_eq!("INVALID, '\u{1f511}' is at both positions 1 and 2", check("1-2 \u{1f511}: \u{1f511}\u{1f511}"));
+    assert_eq!("INVALID, '\u{e9}' appears 3 times, expecting 1 to 2",
+        CountRange.check(&Day02::parse(&mut "1-2 e\u{301}: e\u{301}\u{e9}e\u{301}e".as_bytes()).unwrap()[0]).to_string());
+
+    for bad in &["1-2 ab: abc", "1-2 a abc", "1-2 : abc", "1-2 a:: abc"] {
+        assert!(Day02::parse(&mut bad.as_bytes()).is_err(), "{}", bad);
+    }
+}
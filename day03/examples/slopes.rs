@@ -0,0 +1,87 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::process;
+
+use aoc_common::{diag, Input, Solution};
+use day03::{Day03, Slope};
+
+const USAGE: &str = "Usage: slopes [--slope <right>,<down>]... [--slopes <file>] [--search <max step>] [<input file>|-]";
+
+/// Counts the trees hit on each slope and their product, by default the part 2
+/// slopes. Can also search for the slopes hitting the fewest and most trees, e.g.
+/// cargo run --example slopes -- --slope 3,1 --slope 2,3
+/// cargo run --example slopes -- --search 10
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), Box<dyn Error>> {
+    let mut slopes = Vec::new();
+    let mut max_step = None;
+    let mut input = None;
+
+    let mut args = env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(USAGE);
+
+        match arg.as_str() {
+            "--slope" => {
+                let slope = value()?;
+
+                slopes.push(slope.parse::<Slope>().map_err(|e| format!("Invalid slope '{}': {}", slope, e))?);
+            }
+            "--slopes" => {
+                let path = value()?;
+                let file = fs::File::open(&path).map_err(|e| format!("Unable to open slopes file {}: {}", path, e))?;
+
+                slopes.extend(day03::load_slopes(&mut io::BufReader::new(file)).map_err(|e| e.in_file(&path))?);
+            }
+            "--search" => {
+                let max = value()?;
+
+                match max.parse::<usize>() {
+                    Ok(n) if n > 0 => max_step = Some(n),
+                    _ => Err(format!("Invalid maximum step '{}'", max))?
+                }
+            }
+            _ if input.is_none() && !arg.starts_with("--") => input = Some(arg),
+            _ => Err(USAGE)?
+        }
+    }
+
+    let (name, mut reader) = Input::new(Day03::DAY, input.as_deref()).open()?;
+    let map = Day03::parse(&mut reader).map_err(|e| e.in_file(&name))?;
+
+    diag::set_quiet(true);
+
+    if let Some(max_step) = max_step {
+        let extremes = day03::search(&map, max_step).ok_or("Nothing to search")?;
+
+        let list = |slopes: &[Slope]| slopes.iter().map(|s| s.to_string()).collect::<Vec<_>>().join("; ");
+
+        println!("Fewest trees: {} ({})", extremes.fewest, list(&extremes.fewest_slopes));
+        println!("Most trees: {} ({})", extremes.most, list(&extremes.most_slopes));
+
+        if slopes.is_empty() {
+            return Ok(())
+        }
+    }
+
+    if slopes.is_empty() {
+        slopes = day03::PART2_SLOPES.to_vec();
+    }
+
+    for &slope in &slopes {
+        println!("{}: {} trees", slope, day03::traverse_map(&map, slope));
+    }
+
+    println!("Product: {}", day03::product(&map, &slopes));
+
+    Ok(())
+}
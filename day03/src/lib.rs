@@ -0,0 +1,224 @@
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use num_bigint::BigUint;
+
+use aoc_common::{debug, grid, parse, stat, Grid, ParseError, Solution};
+
+pub struct Day03;
+
+/// Slopes whose tree counts are multiplied for part 2
+pub const PART2_SLOPES: [Slope; 5] = [
+    Slope { right: 1, down: 1 },
+    Slope { right: 3, down: 1 },
+    Slope { right: 5, down: 1 },
+    Slope { right: 7, down: 1 },
+    Slope { right: 1, down: 2 }
+];
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Model = Grid<bool>;
+    type Answer1 = u64;
+    type Answer2 = BigUint;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        grid::parse(input, grid::hash_dot, "'.' or '#'")
+    }
+
+    fn part1(map: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        Ok(traverse_map(map, Slope { right: 3, down: 1 }))
+    }
+
+    fn part2(map: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        Ok(product(map, &PART2_SLOPES))
+    }
+}
+
+/// A toboggan route, moving right then down each step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Slope {
+    right: usize,
+    /// Never 0
+    down: usize
+}
+
+impl Slope {
+    pub fn new(right: usize, down: usize) -> Result<Slope, String> {
+        if down == 0 {
+            Err("down must be at least 1".to_string())?
+        }
+
+        Ok(Slope { right, down })
+    }
+
+    pub fn right(&self) -> usize {
+        self.right
+    }
+
+    pub fn down(&self) -> usize {
+        self.down
+    }
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "right {}, down {}", self.right, self.down)
+    }
+}
+
+impl FromStr for Slope {
+    type Err = String;
+
+    /// Parses '<right>,<down>'
+    fn from_str(s: &str) -> Result<Slope, String> {
+        let mut nums = s.split(',').map(|n| n.trim().parse::<usize>());
+
+        match (nums.next(), nums.next(), nums.next()) {
+            (Some(Ok(right)), Some(Ok(down)), None) => Slope::new(right, down),
+            _ => Err("expecting <right>,<down>".to_string())
+        }
+    }
+}
+
+/// Loads slopes from a file, one '<right>,<down>' per line. Blank lines are ignored
+pub fn load_slopes(input: &mut dyn BufRead) -> Result<Vec<Slope>, ParseError> {
+    let mut slopes = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+        let text = line.text.trim();
+
+        if text.is_empty() {
+            continue
+        }
+
+        slopes.push(text.parse().map_err(|e: String| line.invalid(text, &e))?);
+    }
+
+    Ok(slopes)
+}
+
+/// Counts the trees hit on the slope, recording the count
+pub fn traverse_map(map: &Grid<bool>, slope: Slope) -> u64 {
+    let trees = count_trees(map, slope);
+
+    debug!("Hit {} trees on descent moving x+{}, y+{}", trees, slope.right, slope.down);
+    stat(&format!("trees_right{}_down{}", slope.right, slope.down), trees);
+
+    trees
+}
+
+fn count_trees(map: &Grid<bool>, slope: Slope) -> u64 {
+    if map.width() == 0 {
+        return 0
+    }
+
+    // Map repeats to the right
+    let right = slope.right % map.width();
+
+    let mut x = 0;
+    let mut trees = 0;
+
+    for y in (0..map.height()).step_by(slope.down) {
+        if *map.get_wrapped(x as isize, y as isize) {
+            trees += 1;
+        }
+
+        x = (x + right) % map.width();
+    }
+
+    trees
+}
+
+/// Multiplies the trees hit on each slope
+pub fn product(map: &Grid<bool>, slopes: &[Slope]) -> BigUint {
+    slopes.iter().map(|&slope| BigUint::from(traverse_map(map, slope))).product()
+}
+
+/// The slopes with the fewest and the most trees
+#[derive(Debug, Clone, PartialEq)]
+pub struct Extremes {
+    pub fewest: u64,
+    pub fewest_slopes: Vec<Slope>,
+    pub most: u64,
+    pub most_slopes: Vec<Slope>
+}
+
+/// Tries every slope moving up to max_step right and from 1 to max_step
+/// down, finding those hitting the fewest and the most trees
+pub fn search(map: &Grid<bool>, max_step: usize) -> Option<Extremes> {
+    let mut extremes: Option<Extremes> = None;
+
+    for down in 1..=max_step {
+        for right in 0..=max_step {
+            let slope = Slope { right, down };
+            let trees = count_trees(map, slope);
+
+            let e = extremes.get_or_insert_with(|| Extremes {
+                fewest: trees,
+                fewest_slopes: Vec::new(),
+                most: trees,
+                most_slopes: Vec::new()
+            });
+
+            if trees < e.fewest {
+                e.fewest = trees;
+                e.fewest_slopes.clear();
+            }
+
+            if trees == e.fewest {
+                e.fewest_slopes.push(slope);
+            }
+
+            if trees > e.most {
+                e.most = trees;
+                e.most_slopes.clear();
+            }
+
+            if trees == e.most {
+                e.most_slopes.push(slope);
+            }
+        }
+    }
+
+    extremes
+}
+
+#[test]
+fn test_example() {
+    let model = Day03::parse(&mut include_str!("../../example03.txt").as_bytes()).unwrap();
+
+    assert_eq!(7, Day03::part1(&model).unwrap());
+    assert_eq!(BigUint::from(336u32), Day03::part2(&model).unwrap());
+}
+
+#[test]
+fn slopes_test() {
+    let model = Day03::parse(&mut include_str!("../../example03.txt").as_bytes()).unwrap();
+
+    let slopes = load_slopes(&mut "1,1\n\n 3, 1\n".as_bytes()).unwrap();
+    assert_eq!(vec![Slope { right: 1, down: 1 }, Slope { right: 3, down: 1 }], slopes);
+    assert_eq!(BigUint::from(14u32), product(&model, &slopes));
+
+    for bad in &["3", "3,0", "3,1,1", "x,1", "-1,1"] {
+        assert!(load_slopes(&mut bad.as_bytes()).is_err(), "{}", bad);
+    }
+
+    assert_eq!(Err("down must be at least 1".to_string()), Slope::new(1, 0));
+    assert_eq!("line 2 column 1: invalid '3,0': down must be at least 1", load_slopes(&mut "1,1\n3,0\n".as_bytes()).unwrap_err().to_string());
+
+    // Steps wider than the map wrap round
+    assert_eq!(7, count_trees(&model, Slope { right: 3 + 11 * 1000, down: 1 }));
+
+    // The products of many slopes outgrow 64 bits
+    let many = vec![Slope { right: 3, down: 1 }; 40];
+    assert_eq!(BigUint::from(7u32).pow(40), product(&model, &many));
+
+    let extremes = search(&model, 3).unwrap();
+    assert_eq!((0, vec![Slope { right: 1, down: 3 }, Slope { right: 3, down: 3 }]), (extremes.fewest, extremes.fewest_slopes));
+    assert_eq!((7, vec![Slope { right: 3, down: 1 }]), (extremes.most, extremes.most_slopes));
+    assert_eq!(None, search(&model, 0));
+}
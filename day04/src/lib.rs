@@ -1,26 +1,31 @@
 #[macro_use] extern crate lazy_static;
 extern crate regex;
 
-use std::fs;
-use std::io::{self, BufRead};
+use std::io::BufRead;
 
 use regex::Regex;
 
+use aoc_common::{debug, parse, ParseError, Solution};
+
+pub struct Day04;
+
 macro_rules! struct_reflect {
     (str_struct $name:ident {
         $($field_name:ident,)*
     }) => {
         #[derive(Default)]
-        struct $name {
+        pub struct $name {
             $($field_name: String,)*
         }
 
         impl $name {
-            fn set_field(&mut self, name: &str, value: String) {
+            fn set_field(&mut self, name: &str, value: String) -> Result<(), String> {
                 match(name) {
                     $(stringify!($field_name) => self.$field_name = value),*,
-                    _ => panic!("Field name '{}' not recognised", name)
+                    _ => Err(format!("Field name '{}' not recognised", name))?
                 }
+
+                Ok(())
             }
         }
     }
@@ -40,18 +45,28 @@ struct_reflect! {
 }
 
 impl Cred {
-    pub fn is_valid(&self) -> Result<(), Box<dyn std::error::Error>> {
+    fn is_present(&self) -> bool {
+        !self.byr.is_empty() &&
+        !self.iyr.is_empty() &&
+        !self.eyr.is_empty() &&
+        !self.hgt.is_empty() &&
+        !self.hcl.is_empty() &&
+        !self.ecl.is_empty() &&
+        !self.pid.is_empty()
+    }
+
+    fn is_valid(&self) -> Result<(), Box<dyn std::error::Error>> {
         // byr (Birth Year) - four digits; at least 1920 and at most 2002.
         let byr = self.byr.parse::<u16>()?;
-        if byr < 1920 || byr > 2002 { Err(format!("Invalid byr: {}", self.byr))? }
+        if !(1920..=2002).contains(&byr) { Err(format!("Invalid byr: {}", self.byr))? }
 
         // iyr (Issue Year) - four digits; at least 2010 and at most 2020.
         let iyr = self.iyr.parse::<u16>()?;
-        if iyr < 2010 || iyr > 2020 { Err(format!("Invalid iyr: {}", self.iyr))? }
+        if !(2010..=2020).contains(&iyr) { Err(format!("Invalid iyr: {}", self.iyr))? }
 
         // eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
         let eyr = self.eyr.parse::<u16>()?;
-        if eyr < 2020 || eyr > 2030 { Err(format!("Invalid eyr: {}", self.eyr))? }
+        if !(2020..=2030).contains(&eyr) { Err(format!("Invalid eyr: {}", self.eyr))? }
 
         // hgt (Height) - a number followed by either cm or in:
         //    If cm, the number must be at least 150 and at most 193.
@@ -69,10 +84,10 @@ impl Cred {
                 let unit = cap.get(2).unwrap();
                 match unit.as_str() {
                     "cm" => {
-                        if value < 150 || value > 193 { Err(format!("Invalid cm hgt: {}", value))? }
+                        if !(150..=193).contains(&value) { Err(format!("Invalid cm hgt: {}", value))? }
                     },
                     "in" => {
-                        if value < 59 || value > 76 { Err(format!("Invalid in hgt: {}", value))? }
+                        if !(59..=76).contains(&value) { Err(format!("Invalid in hgt: {}", value))? }
                     }
                     _ => {
                         Err(format!("Invalid hgt unit: {}", unit.as_str()))?
@@ -111,38 +126,44 @@ impl Cred {
     }
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let creds = load_creds()?;
+impl Solution for Day04 {
+    const DAY: u8 = 4;
 
-    let mut valid: i16 = 0;
+    type Model = Vec<Cred>;
+    type Answer1 = u16;
+    type Answer2 = u16;
 
-    for cred in creds {
-        match cred.is_valid() {
-            Ok(_) => { valid += 1},
-            Err(e) => { println!("{}", e) }
-        }
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_creds(input)
     }
 
-    println!("{} valid credentials", valid);
+    fn part1(creds: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        Ok(creds.iter().filter(|cred| cred.is_present()).count() as u16)
+    }
 
-    Ok(())
-}
+    fn part2(creds: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let mut valid: u16 = 0;
 
-fn load_creds() -> Result<Vec<Cred>, Box<dyn std::error::Error>> {
-    // Open the file read only
-    let input = fs::File::open("../input04.txt")?;
+        for cred in creds {
+            match cred.is_valid() {
+                Ok(_) => { valid += 1},
+                Err(e) => { debug!("{}", e) }
+            }
+        }
 
-    // Create a buffered reader on the file
-    let inputbuf = io::BufReader::new(input);
+        Ok(valid)
+    }
+}
 
+fn load_creds(input: &mut dyn BufRead) -> Result<Vec<Cred>, ParseError> {
     let mut creds = Vec::new();
 
     let mut cred_opt: Option<Cred> = None;
 
-    for line_result in inputbuf.lines() {
+    for line_result in parse::lines(input) {
         let line = line_result?;
 
-        if line.is_empty() {
+        if line.text.is_empty() {
             if cred_opt.is_some() {
                 creds.push(cred_opt.unwrap());
                 cred_opt = None;
@@ -156,25 +177,37 @@ fn load_creds() -> Result<Vec<Cred>, Box<dyn std::error::Error>> {
                 None => cred = Default::default()
             }
 
-            let elems = line.split_whitespace();
+            let elems = line.text.split_whitespace();
             
             for elem in elems {
                 let terms: Vec<_> = elem.split(":").collect();
 
                 if terms.len() != 2 {
-                    Err(format!("Invalid term: {}", elem))?
+                    Err(line.unexpected(elem, "name:value"))?
                 }
 
-                cred.set_field(terms[0], terms[1].to_string());
+                if let Err(e) = cred.set_field(terms[0], terms[1].to_string()) {
+                    Err(line.invalid(terms[0], &e))?
+                }
             }
 
             cred_opt = Some(cred)
         }
     }
 
-    if cred_opt.is_some() {
-        creds.push(cred_opt.unwrap())
+    if let Some(cred) = cred_opt {
+        creds.push(cred)
     }
 
     Ok(creds)
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_example() {
+    let model = Day04::parse(&mut include_str!("../../example04.txt").as_bytes()).unwrap();
+    assert_eq!(2, Day04::part1(&model).unwrap());
+
+    // Four invalid passports followed by four valid ones
+    let model = Day04::parse(&mut include_str!("../../example04-2.txt").as_bytes()).unwrap();
+    assert_eq!(4, Day04::part2(&model).unwrap());
+}
@@ -0,0 +1,159 @@
+use std::io::BufRead;
+
+use aoc_common::{parse, ParseError, Solution};
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+pub struct Day05;
+
+const ROWS: u8 = 128;
+const COLS: u8 = 8;
+const SEATS: u16 = seat_from_position((ROWS - 1, COLS - 1)) + 1;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Model = Vec<bool>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        let mut seats = vec![false; SEATS as usize];
+
+        for line_result in parse::lines(input) {
+            let line = line_result?;
+
+            if line.text.len() != 10 {
+                Err(line.invalid(&line.text, "expecting 10 characters"))?
+            }
+
+            let pos = match position_from_chop(&line.text) {
+                Ok(pos) => pos,
+                Err(i) => {
+                    let c = line.text[i..].chars().next().unwrap();
+                    Err(line.unexpected(&line.text[i..i + c.len_utf8()], "'F', 'B', 'L' or 'R'"))?
+                }
+            };
+
+            let seat = seat_from_position(pos);
+
+            seats[seat as usize] = true;
+        }
+
+        Ok(seats)
+    }
+
+    fn part1(seats: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let mut highest: u16 = 0;
+
+        for (elem, seat) in seats.iter().enumerate() {
+            if *seat {
+                highest = elem as u16;
+            }
+        }
+
+        Ok(highest)
+    }
+
+    fn part2(seats: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let mut got_first: bool = false;
+
+        for (elem, seat) in seats.iter().enumerate() {
+            if *seat {
+                got_first = true;
+            } else if got_first {
+                return Ok(elem as u16)
+            }
+        }
+
+        Err("Free seat not found")?
+    }
+}
+
+/// Works out the row and column, or the position of an invalid direction
+fn position_from_chop(chop: &str) -> Result<(u8, u8), usize> {
+    let mut ymin: u8 = 0;
+    let mut ymax: u8 = ROWS - 1;
+    let mut xmin: u8 = 0;
+    let mut xmax: u8 = COLS - 1;
+
+    for (i, dir) in chop.char_indices() {
+        match dir {
+            'F' => { let cnt = (ymax - ymin).div_ceil(2); ymax -= cnt; },
+            'B' => { let cnt = (ymax - ymin).div_ceil(2); ymin += cnt; },
+            'L' => { let cnt = (xmax - xmin).div_ceil(2); xmax -= cnt; },
+            'R' => { let cnt = (xmax - xmin).div_ceil(2); xmin += cnt; },
+            _ => return Err(i)
+        }
+    }
+
+    Ok((ymin, xmin))
+}
+
+#[test]
+fn position_from_chop_test() {
+    assert_eq!(Ok((44, 5)), position_from_chop("FBFBBFFRLR"));
+    assert_eq!(Ok((70, 7)), position_from_chop("BFFFBBFRRR"));
+    assert_eq!(Ok((14, 7)), position_from_chop("FFFBBBFRRR"));
+    assert_eq!(Ok((102, 4)), position_from_chop("BBFFBBFRLL"));
+    assert_eq!(Err(3), position_from_chop("BBFXBBFRLL"));
+}
+
+const fn seat_from_position(pos: (u8, u8)) -> u16 {
+    ((pos.0 as u16) * 8) + pos.1 as u16
+}
+
+#[test]
+fn seat_from_position_test() {
+    assert_eq!(357, seat_from_position((44, 5)));
+    assert_eq!(567, seat_from_position((70, 7)));
+    assert_eq!(119, seat_from_position((14, 7)));
+    assert_eq!(820, seat_from_position((102, 4)));
+}
+
+#[test]
+fn bad_char_test() {
+    // 10 bytes with a multi-byte character
+    let err = Day05::parse(&mut "FBFéBBFRL\n".as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("'é'"), "{}", err);
+}
+
+#[test]
+fn test_example() {
+    // Part 2 has no published example
+    let model = Day05::parse(&mut include_str!("../../example05.txt").as_bytes()).unwrap();
+    assert_eq!(820, Day05::part1(&model).unwrap());
+}
+
+/// Builds the directions to a seat, the inverse of position_from_chop
+#[cfg(test)]
+fn chop_from_position((row, col): (u8, u8)) -> String {
+    let rows = (0..7).rev().map(|bit| if row & (1 << bit) == 0 { 'F' } else { 'B' });
+    let cols = (0..3).rev().map(|bit| if col & (1 << bit) == 0 { 'L' } else { 'R' });
+
+    rows.chain(cols).collect()
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn position_round_trip_prop(row in 0..ROWS, col in 0..COLS) {
+        let chop = chop_from_position((row, col));
+
+        prop_assert_eq!(Ok((row, col)), position_from_chop(&chop));
+
+        let seat = seat_from_position((row, col));
+
+        prop_assert!(seat < SEATS);
+        prop_assert_eq!((row as u16, col as u16), (seat / COLS as u16, seat % COLS as u16));
+    }
+
+    #[test]
+    fn position_bad_char_prop(row in 0..ROWS, col in 0..COLS, pos in 0..10usize, c in "[^FBLR]") {
+        let mut chop = chop_from_position((row, col));
+        chop.replace_range(pos..pos + 1, &c);
+
+        prop_assert_eq!(Err(pos), position_from_chop(&chop));
+    }
+}
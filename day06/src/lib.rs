@@ -0,0 +1,100 @@
+use std::io::BufRead;
+
+use aoc_common::{parse, Line, ParseError, Solution};
+
+pub struct Day06;
+
+/// Answer bitmasks for each person in a group
+type Group = Vec<u32>;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Model = Vec<Group>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_answers(input)
+    }
+
+    fn part1(groups: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        // Count questions anyone in the group answered
+        Ok(groups.iter().map(|group| {
+            group.iter().fold(0, |acc, bitmask| acc | bitmask).count_ones()
+        }).sum())
+    }
+
+    fn part2(groups: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        // Count questions everyone in the group answered
+        Ok(groups.iter().map(|group| {
+            group.iter().fold(u32::MAX, |acc, bitmask| acc & bitmask).count_ones()
+        }).sum())
+    }
+}
+
+fn load_answers(input: &mut dyn BufRead) -> Result<Vec<Group>, ParseError> {
+    let mut groups = Vec::new();
+    let mut group: Group = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        if line.text.is_empty() {
+            if !group.is_empty() {
+                groups.push(group);
+                group = Vec::new();
+            }
+
+        } else {
+            group.push(process_line(&line)?);
+
+        }
+    }
+
+    if !group.is_empty() {
+        groups.push(group);
+    }
+
+    Ok(groups)
+}
+
+fn process_line(line: &Line) -> Result<u32, ParseError> {
+    let mut bitmask: u32 = 0;
+
+    for (i, c) in line.text.char_indices() {
+        if !c.is_ascii_lowercase() {
+            Err(line.unexpected(&line.text[i..i + c.len_utf8()], "a question letter 'a' to 'z'"))?
+        }
+
+        let charno = c as u32 - ('a' as u32);
+        let bit = 1 << charno;
+        bitmask |= bit;
+    }
+
+    Ok(bitmask)
+}
+
+#[test]
+fn process_line_test() {
+    let mut bitmask: u32;
+    
+    bitmask = process_line(&Line::new(1, "a")).unwrap();
+    assert!(bitmask == 1, "bitmask for \"a\" incorrect ({})", bitmask);
+
+    bitmask = process_line(&Line::new(1, "b")).unwrap();
+    assert!(bitmask == 2, "bitmask for \"b\" incorrect ({})", bitmask);
+
+    bitmask = process_line(&Line::new(1, "ab")).unwrap();
+    assert!(bitmask == 3, "bitmask for \"ab\" incorrect ({})", bitmask);
+
+    assert!(process_line(&Line::new(1, "aB")).is_err(), "\"aB\" should fail");
+}
+
+#[test]
+fn test_example() {
+    let model = Day06::parse(&mut include_str!("../../example06.txt").as_bytes()).unwrap();
+
+    assert_eq!(11, Day06::part1(&model).unwrap());
+    assert_eq!(6, Day06::part2(&model).unwrap());
+}
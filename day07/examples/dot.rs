@@ -0,0 +1,21 @@
+use std::process;
+
+use aoc_common::Input;
+
+/// Prints the bag rules as a Graphviz DOT graph, e.g.
+/// cargo run --example dot | dot -Tsvg > bags.svg
+fn main() {
+    let input = Input::from_args(7);
+
+    let result = input.open().map_err(|e| e.to_string()).and_then(|(name, mut reader)| {
+        day07::load_rules(&mut reader).map_err(|e| e.in_file(&name).to_string())
+    });
+
+    match result {
+        Ok(rules) => print!("{}", rules.to_dot("bags", |qty| Some(qty.to_string()))),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::io::BufRead;
+
+use aoc_common::graph::Graph;
+use aoc_common::{parse, ParseError, Solution};
+
+pub struct Day07;
+
+const COLOUR: &str = "shiny gold";
+
+/// Bag colours with edges to the colours they directly contain, weighted by quantity
+pub type Rules = Graph<u16>;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Model = Rules;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_rules(input)
+    }
+
+    fn part1(rules: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        // Colours which eventually contain the bag
+        Ok(rules.ancestors(colour(rules)?).len())
+    }
+
+    fn part2(rules: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        Ok(rules.path_products(colour(rules)?)?)
+    }
+}
+
+fn colour(rules: &Rules) -> Result<usize, String> {
+    rules.id(COLOUR).ok_or_else(|| format!("No rule mentions {} bags", COLOUR))
+}
+
+pub fn load_rules(input: &mut dyn BufRead) -> Result<Rules, ParseError> {
+    let mut rules = Graph::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let mut rule_split = line.text.split(" bags contain ");
+
+        let colour = rules.node(line.next(&mut rule_split, "bag colour")?);
+        let contents = line.next(&mut rule_split, "' bags contain '")?;
+
+        if contents != "no other bags." {
+            for s in contents.split(", ") {
+                let content = match s.find(" bag") {
+                    Some(pos) => &s[..pos],
+                    None => Err(line.unexpected(s, "'<quantity> <colour> bag(s)'"))?
+                };
+
+                let mut term = content.splitn(2, ' ');
+                let qty = line.parse::<u16>(line.next(&mut term, "quantity")?)?;
+                let inner = line.next(&mut term, "bag colour")?;
+
+                let inner = rules.node(inner);
+                rules.add_edge(colour, inner, qty);
+            }
+        }
+    }
+
+    Ok(rules)
+}
+
+#[test]
+fn test_example() {
+    let model = Day07::parse(&mut include_str!("../../example07.txt").as_bytes()).unwrap();
+    assert_eq!(4, Day07::part1(&model).unwrap());
+    assert_eq!(32, Day07::part2(&model).unwrap());
+
+    let model = Day07::parse(&mut include_str!("../../example07-2.txt").as_bytes()).unwrap();
+    assert_eq!(126, Day07::part2(&model).unwrap());
+}
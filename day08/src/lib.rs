@@ -0,0 +1,199 @@
+use std::io::BufRead;
+
+use aoc_common::{debug, parse, stat, ParseError, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Model = Vec<Instruction>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_program(input)
+    }
+
+    fn part1(program: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        match execute_program(program)? {
+            Exit::Loop(pc, acc) => {
+                debug!("Loop found at pc {}. acc is {}", pc, acc);
+                stat("loop_pc", pc);
+                Ok(acc)
+            },
+            Exit::Finished(_) => Err("Program finished without looping")?
+        }
+    }
+
+    fn part2(program: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let mut program = program.clone();
+        let mut corrupted: Vec<usize> = Vec::new();
+
+        for (i, inst) in program.iter().enumerate() {
+            match inst.op {
+                OpCode::nop | OpCode::jmp => corrupted.push(i),
+                _ => {}
+            }
+        }
+
+        debug!("{} possibly corrupted instructions", corrupted.len());
+        stat("candidates", corrupted.len());
+
+        for i in corrupted {
+            let saveop: OpCode = program[i].op;
+
+            match program[i].op {
+                OpCode::nop => program[i].op = OpCode::jmp,
+                OpCode::jmp => program[i].op = OpCode::nop,
+                _ => panic!("Unexpected opcode")
+            }
+
+            match execute_program(&program) {
+                Ok(Exit::Finished(acc)) => {
+                    debug!("Program finished changing instruction {}. acc is {}", i, acc);
+                    stat("changed_instruction", i);
+                    return Ok(acc)
+                },
+                Ok(Exit::Loop(pc, acc)) => {
+                    debug!("Loop found at pc {}. acc is {}", pc, acc);
+                },
+                Err(e) => {
+                    debug!("{}", e);
+                },
+            }
+
+            program[i].op = saveop;
+        }
+
+        Err("No single instruction change lets the program finish")?
+    }
+}
+
+macro_rules! inst_build {
+    ($name:ident {
+        $($field_name:ident,)*
+    }) => {
+        #[derive(Debug, Copy, Clone)]
+        #[allow(non_camel_case_types)]
+        pub enum $name {
+            $($field_name,)*
+        }
+
+        impl $name {
+            fn from_string(name: &str) -> Option<$name> {
+                match name {
+                    $(stringify!($field_name) => Some($name::$field_name)),*,
+                    _ => None
+                }
+            }
+        }
+    }
+}
+
+inst_build! {
+    OpCode {
+        nop,
+        acc,
+        jmp,
+    }
+}
+
+#[derive(Clone)]
+pub struct Instruction {
+    op: OpCode,
+    arg: i32
+}
+
+fn load_program(input: &mut dyn BufRead) -> Result<Vec<Instruction>, ParseError> {
+    let mut program = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let mut split = line.text.split_whitespace();
+
+        let str_inst = line.next(&mut split, "opcode")?;
+        let inst = match OpCode::from_string(str_inst) {
+            Some(inst) => inst,
+            None => Err(line.unexpected(str_inst, "'nop', 'acc' or 'jmp'"))?
+        };
+        let arg = line.parse::<i32>(line.next(&mut split, "argument")?)?;
+
+        let inst = Instruction {
+            op: inst,
+            arg
+        };
+
+        program.push(inst);
+    }
+
+    Ok(program)
+}
+
+struct ExecState {
+    pc: i32,
+    acc: i32,
+}
+
+/// How a program run ended
+enum Exit {
+    /// Instruction about to be executed twice (pc, acc)
+    Loop(usize, i32),
+    /// Ran off the end of the program (acc)
+    Finished(i32)
+}
+
+fn execute_program(program: &[Instruction]) -> Result<Exit, String> {
+    let prog_len = program.len();
+    let mut touched = vec![false; prog_len];
+    let mut state = ExecState {
+        pc: 0,
+        acc: 0
+    };
+
+    loop {
+        if state.pc < 0 || state.pc as usize > prog_len {
+            Err(format!("pc out of bounds: {}", state.pc))?
+        }
+
+        let pc = state.pc as usize;
+
+        if pc == prog_len {
+            break
+        }
+
+        if touched[pc] {
+            return Ok(Exit::Loop(pc, state.acc));
+        }
+
+        touched[pc] = true;
+
+        let op = &program[pc].op;
+        let arg = program[pc].arg;
+
+        // println!("{}: {:?} {}", pc, op, arg);
+
+        state.pc += 1;
+
+        match op {
+            OpCode::nop => {},
+            OpCode::acc => {
+                state.acc += arg;
+            },
+            OpCode::jmp => {
+                state.pc += arg - 1;
+            }
+        }
+    }
+
+    Ok(Exit::Finished(state.acc))
+}
+
+#[test]
+fn test_example() {
+    let model = Day08::parse(&mut include_str!("../../example08.txt").as_bytes()).unwrap();
+
+    assert_eq!(5, Day08::part1(&model).unwrap());
+    assert_eq!(8, Day08::part2(&model).unwrap());
+}
@@ -0,0 +1,129 @@
+use std::io::BufRead;
+use std::cmp;
+
+use aoc_common::{debug, parse, stat, ParseError, Solution};
+
+pub struct Day09;
+
+const PREAMBLE: usize = 25;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Model = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_numbers(input)
+    }
+
+    fn part1(numbers: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let (i, target) = find_invalid_number(numbers, PREAMBLE).ok_or("All numbers have a sum")?;
+
+        debug!("Element {} ({}) does not have a sum", i + 1, target);
+        stat("element", i + 1);
+
+        Ok(target)
+    }
+
+    fn part2(numbers: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let (_, target) = find_invalid_number(numbers, PREAMBLE).ok_or("All numbers have a sum")?;
+
+        let range = find_range(target, numbers).ok_or(format!("No range sums to {}", target))?;
+
+        stat("range_start", range.0 + 1);
+        stat("range_end", range.1 + 1);
+
+        Ok(calc_result(numbers, range))
+    }
+}
+
+fn find_invalid_number(numbers: &[u64], preamble: usize) -> Option<(usize, u64)> {
+    let mut result = None;
+
+    for i in preamble..(numbers.len() - 1) {
+        let target = numbers[i];
+        let mut found = false;
+
+        for j in (((i + 1) - preamble)..i).rev() {
+            for k in (((i + 1) - (preamble + 1))..j).rev() {
+                if numbers[j] + numbers[k] == target {
+                    found = true;
+                    break
+                }
+            }
+        }
+
+        if !found {
+            result = Some((i, numbers[i]));
+            break
+        }
+    }
+
+    result
+}
+
+fn find_range(target: u64, numbers: &[u64]) -> Option<(usize, usize)> {
+    let mut result = None;
+    let mut finished = false;
+
+    for i in 0..numbers.len() {
+        let mut total: u64 = numbers[i];
+
+        for (j, number) in numbers.iter().enumerate().skip(i + 1) {
+            total += number;
+
+            if total >= target {
+                if total == target {
+                    result = Some((i, j));
+                    finished = true
+                }
+                break
+            }
+        }
+
+        if finished {
+            break
+        }
+    }
+
+    result
+}
+
+fn calc_result(numbers: &[u64], range: (usize, usize)) -> u64 {
+    let mut min: u64 = u64::MAX;
+    let mut max: u64 = 0;
+
+    for &number in &numbers[range.0..range.1] {
+        min = cmp::min(min, number);
+        max = cmp::max(max, number);
+    }
+
+    min + max
+}
+
+fn load_numbers(input: &mut dyn BufRead) -> Result<Vec<u64>, ParseError> {
+    let mut numbers = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let number = line.parse::<u64>(&line.text)?;
+
+        numbers.push(number);
+    }
+
+    Ok(numbers)
+}
+
+#[test]
+fn test_example() {
+    // The example uses a preamble of 5
+    let numbers = Day09::parse(&mut include_str!("../../example09.txt").as_bytes()).unwrap();
+
+    assert_eq!(Some((14, 127)), find_invalid_number(&numbers, 5));
+
+    let range = find_range(127, &numbers).unwrap();
+    assert_eq!(62, calc_result(&numbers, range));
+}
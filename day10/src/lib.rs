@@ -0,0 +1,110 @@
+use std::io::BufRead;
+
+use aoc_common::{debug, parse, stat, ParseError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Model = Vec<u8>;
+    type Answer1 = u16;
+    type Answer2 = u64;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        let mut numbers = load_numbers(input)?;
+
+        numbers.sort();
+
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let mut last: u8 = 0;
+        let mut ones: u8 = 0;
+        let mut threes: u8 = 1; // = 1 for jump from last jolt to tthe device
+
+        for &n in numbers {
+            match n - last {
+                1 => { ones += 1},
+                3 => { threes += 1 },
+                _ => panic!("Unexpected diff ({})", n - last)
+            }
+
+            last = n;
+        }
+
+        debug!("{} ones, {} threes", ones, threes);
+        stat("ones", ones);
+        stat("threes", threes);
+
+        Ok(ones as u16 * threes as u16)
+    }
+
+    fn part2(numbers: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let mut answer: u64 = 1;
+        let mut last: u8 = 0;
+        let mut start: usize = 0;
+
+        for (elem, n) in numbers.iter().enumerate() {
+            match *n - last {
+                1 => {},
+                3 => { 
+                    let mut range = elem - start;
+
+                    if start == 0 {
+                        range += 1
+                    }
+
+                    process_range(&mut answer, range);
+
+                    start = elem;
+                },
+                _ => panic!("Unexpected diff ({})", n - last)
+            }
+
+            last = *n;
+        }
+
+        process_range(&mut answer, numbers.len() - start);
+
+        Ok(answer)
+    }
+}
+
+fn process_range(answer: &mut u64, range: usize) {
+    debug!("Range of {}", range);
+
+    match range {
+        1 | 2 => {},      // 1
+        3 => *answer *= 2, // 1->2->3, 1->3
+        4 => *answer *= 4, // 1->2->3->4, 1->2->4, 1->3->4, 1->4
+        5 => *answer *= 7, // 1->2->3->4->5, 1->2->3->5, 1->2->4->5, 1->2->5, 1->3->4->5, 1->3->5, 1->4->5
+        _ => panic!("Unhandled range {}", range)
+    }
+}
+
+fn load_numbers(input: &mut dyn BufRead) -> Result<Vec<u8>, ParseError> {
+    let mut numbers = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let number = line.parse::<u8>(&line.text)?;
+
+        numbers.push(number);
+    }
+
+    Ok(numbers)
+}
+
+#[test]
+fn test_example() {
+    let model = Day10::parse(&mut include_str!("../../example10.txt").as_bytes()).unwrap();
+    assert_eq!(7 * 5, Day10::part1(&model).unwrap());
+    assert_eq!(8, Day10::part2(&model).unwrap());
+
+    let model = Day10::parse(&mut include_str!("../../example10-2.txt").as_bytes()).unwrap();
+    assert_eq!(22 * 10, Day10::part1(&model).unwrap());
+    assert_eq!(19208, Day10::part2(&model).unwrap());
+}
@@ -0,0 +1,84 @@
+use std::io::BufRead;
+
+use aoc_common::ca::{Automaton, LineOfSight, Outcome, Rect, Rule, Topology};
+use aoc_common::{debug, grid, stat, Grid, ParseError, Solution};
+
+pub struct Day11;
+
+#[derive(Debug, Clone, Hash, PartialEq)]
+pub enum LayoutState {
+    Floor,
+    Empty,
+    Occupied
+}
+
+type Layout = Grid<LayoutState>;
+
+/// Give up if the seating hasn't settled by then
+const MAX_ITERATIONS: usize = 10_000;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Model = Layout;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_layout(input)
+    }
+
+    fn part1(layout: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        settle_layout(layout, Rect::new(layout, is_seat), 4)
+    }
+
+    fn part2(layout: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        settle_layout(layout, LineOfSight::new(layout, is_seat), 5)
+    }
+}
+
+fn is_seat(state: &LayoutState) -> bool {
+    *state != LayoutState::Floor
+}
+
+/// Fills seats until nothing changes. Empty seats with no occupied neighbours
+/// fill, occupied seats with tolerance or more occupied neighbours empty
+fn settle_layout<T>(layout: &Layout, neighbours: T, tolerance: usize) -> Result<usize, Box<dyn std::error::Error>>
+where T: Topology<Cell = (usize, usize)> {
+    let occupied = layout.iter()
+        .filter(|(_, s)| **s == LayoutState::Occupied)
+        .map(|(pos, _)| pos);
+
+    let rule = Rule::new(&[0], &(0..tolerance).collect::<Vec<_>>());
+
+    let mut automaton = Automaton::new(neighbours, rule, occupied);
+
+    match automaton.settle(MAX_ITERATIONS) {
+        Outcome::Fixed(_) => (),
+        outcome => Err(format!("Seating did not settle: {:?}", outcome))?
+    }
+
+    let iteration = automaton.generation();
+    let occupied = automaton.live().len();
+
+    debug!("Stable after {} iterations, {} seats occupied", iteration, occupied);
+    stat("iterations", iteration);
+
+    Ok(occupied)
+}
+
+fn load_layout(input: &mut dyn BufRead) -> Result<Layout, ParseError> {
+    grid::parse(input, |c| match c {
+        '.' => Some(LayoutState::Floor),
+        'L' => Some(LayoutState::Empty),
+        _ => None
+    }, "'.' or 'L'")
+}
+
+#[test]
+fn test_example() {
+    let model = Day11::parse(&mut include_str!("../../example11.txt").as_bytes()).unwrap();
+
+    assert_eq!(37, Day11::part1(&model).unwrap());
+    assert_eq!(26, Day11::part2(&model).unwrap());
+}
@@ -0,0 +1,152 @@
+use std::io::BufRead;
+
+use aoc_common::{parse, ParseError, Solution};
+
+pub struct Day12;
+
+pub enum Action {
+    North(u16),
+    South(u16),
+    East(u16),
+    West(u16),
+    Left(u16),
+    Right(u16),
+    Forward(u16)
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Model = Vec<Action>;
+    type Answer1 = i16;
+    type Answer2 = i32;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_actions(input)
+    }
+
+    fn part1(actions: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let xy = take_actions(actions);
+
+        Ok(i16::abs(xy.0) + i16::abs(xy.1))
+    }
+
+    fn part2(actions: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let xy = take_waypoint_actions(actions);
+
+        Ok(i32::abs(xy.0) + i32::abs(xy.1))
+    }
+}
+
+fn load_actions(input: &mut dyn BufRead) -> Result<Vec<Action>, ParseError> {
+    let mut actions = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let action_char = match line.text.chars().next() {
+            Some(c) => c,
+            None => Err(line.missing("action"))?
+        };
+        let action_str = &line.text[..action_char.len_utf8()];
+        let value = &line.parse::<u16>(&line.text[action_char.len_utf8()..])?;
+
+        let action: Action = match action_char {
+            'N' => Action::North(*value),
+            'S' => Action::South(*value),
+            'E' => Action::East(*value),
+            'W' => Action::West(*value),
+            'L' => Action::Left(*value),
+            'R' => Action::Right(*value),
+            'F' => Action::Forward(*value),
+            _ => Err(line.unexpected(action_str, "'N', 'S', 'E', 'W', 'L', 'R' or 'F'"))?
+        };
+
+        actions.push(action);
+    }
+
+    Ok(actions)
+}
+
+/// Actions move the ship directly
+fn take_actions(actions: &[Action]) -> (i16, i16) {
+    let mut bearing: i16 = 90;
+
+    let mut xy: (i16, i16) = (0, 0);
+
+    for action in actions {
+        match *action {
+            Action::North(dist) => xy.1 += dist as i16,
+            Action::South(dist) => xy.1 -= dist as i16,
+            Action::East(dist) => xy.0 += dist as i16,
+            Action::West(dist) => xy.0 -= dist as i16,
+            Action::Left(deg) => {
+                bearing -= deg as i16;
+                while bearing < 0 {
+                    bearing += 360
+                }
+            }
+            Action::Right(deg) => bearing = (bearing + deg as i16) % 360,
+            Action::Forward(dist) => {
+                match bearing {
+                    0 => xy.1 += dist as i16,
+                    90 => xy.0 += dist as i16,
+                    180 => xy.1 -= dist as i16,
+                    270 => xy.0 -= dist as i16,
+                    _ => panic!("Unhandled bearing {}", bearing)
+                }
+            }
+        };
+    }
+
+    xy
+}
+
+/// Actions move a waypoint relative to the ship
+fn take_waypoint_actions(actions: &[Action]) -> (i32, i32) {
+    let mut waypoint: (i16, i16) = (10, 1);
+    let mut xy: (i32, i32) = (0, 0);
+
+    for action in actions {
+        match *action {
+            Action::North(dist) => waypoint.1 += dist as i16,
+            Action::South(dist) => waypoint.1 -= dist as i16,
+            Action::East(dist) => waypoint.0 += dist as i16,
+            Action::West(dist) => waypoint.0 -= dist as i16,
+            Action::Left(deg) => {
+                let save_waypoint = waypoint;
+
+                match deg {
+                    90 => {waypoint.0 = -save_waypoint.1; waypoint.1 = save_waypoint.0},
+                    180 => {waypoint.0 = -save_waypoint.0; waypoint.1 = -save_waypoint.1},
+                    270 => {waypoint.0 = save_waypoint.1; waypoint.1 = -save_waypoint.0},
+                    _ => panic!("Unhandled Left {}", deg)
+                }
+            }
+            Action::Right(deg) => {
+                let save_waypoint = waypoint;
+
+                match deg {
+                    270 => {waypoint.0 = -save_waypoint.1; waypoint.1 = save_waypoint.0},
+                    180 => {waypoint.0 = -save_waypoint.0; waypoint.1 = -save_waypoint.1},
+                    90 => {waypoint.0 = save_waypoint.1; waypoint.1 = -save_waypoint.0},
+                    _ => panic!("Unhandled Left {}", deg)
+                }
+            },
+            Action::Forward(dist) => {
+                xy.0 += dist as i32 * waypoint.0 as i32;
+                xy.1 += dist as i32 * waypoint.1 as i32;
+            }
+        };
+    }
+
+    xy
+}
+
+#[test]
+fn test_example() {
+    let model = Day12::parse(&mut include_str!("../../example12.txt").as_bytes()).unwrap();
+
+    assert_eq!(25, Day12::part1(&model).unwrap());
+    assert_eq!(286, Day12::part2(&model).unwrap());
+}
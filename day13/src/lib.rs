@@ -0,0 +1,99 @@
+use std::io::BufRead;
+
+use aoc_common::numtheory::crt;
+use aoc_common::{debug, parse, stat, ParseError, Solution};
+
+pub struct Day13;
+
+type IntType = i128;
+
+pub struct Notes {
+    depart_ts: u32,
+    /// Bus IDs with their offset in the list
+    times: Vec<(usize, u32)>
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Model = Notes;
+    type Answer1 = u32;
+    type Answer2 = IntType;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_info(input)
+    }
+
+    fn part1(notes: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let depart_ts = notes.depart_ts;
+
+        let wait_info: Vec<_> = notes.times.iter().map(|&(_, id)| {
+            (id, id - (depart_ts % id))
+        }).collect();
+
+        debug!("{:?}", wait_info);
+
+        let min = wait_info.iter().fold((0, u32::MAX), |minacc, elem| {
+            if elem.1 < minacc.1 {
+                return *elem;
+            }
+            minacc
+        });
+
+        debug!("Bus {} is next, wait time {}", min.0, min.1);
+        stat("bus", min.0);
+        stat("wait", min.1);
+
+        Ok(min.0 * min.1)
+    }
+
+    fn part2(notes: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let times: Vec<(IntType, IntType)> = notes.times.iter().map(|&(o, p)| {
+            (o as IntType, p as IntType)
+        }).collect();
+
+        debug!("{:?}", times);
+
+        // Bus with ID p at offset o departs at t + o, so t = -o modulo p
+        let equations: Vec<_> = times.iter().map(|&(o, p)| (-o, p)).collect();
+
+        let (ts, period) = crt(&equations).map_err(|e| format!("Can't solve bus times: {}", e))?;
+
+        debug!("Buses line up every {} minutes", period);
+
+        Ok(ts)
+    }
+}
+
+fn load_info(input: &mut dyn BufRead) -> Result<Notes, ParseError> {
+    let mut lines = parse::lines(input);
+
+    let depart_line = lines.expect("departure time")?;
+    let depart_ts = depart_line.parse::<u32>(&depart_line.text)?;
+
+    let mut times: Vec<_> = Vec::new();
+
+    let id_line = lines.expect("bus IDs")?;
+
+    for (elem, id) in id_line.text.split(",").enumerate() {
+        if id != "x" {
+            times.push((elem, id_line.parse::<u32>(id)?));
+        }
+    }
+
+    Ok(Notes { depart_ts, times })
+}
+
+#[test]
+fn test_example() {
+    let model = Day13::parse(&mut include_str!("../../example13.txt").as_bytes()).unwrap();
+    assert_eq!(295, Day13::part1(&model).unwrap());
+    assert_eq!(1068781, Day13::part2(&model).unwrap());
+
+    // Further part 2 examples (the departure time is not used)
+    for (ids, ts) in &[("17,x,13,19", 3417), ("67,7,59,61", 754018), ("67,x,7,59,61", 779210),
+                       ("67,7,x,59,61", 1261476), ("1789,37,47,1889", 1202161486)] {
+        let model = Day13::parse(&mut format!("0\n{}\n", ids).as_bytes()).unwrap();
+        assert_eq!(*ts, Day13::part2(&model).unwrap(), "{} should depart at {}", ids, ts);
+    }
+}
@@ -0,0 +1,170 @@
+use std::io::BufRead;
+use std::collections::HashMap;
+#[cfg(test)]
+use std::collections::HashSet;
+
+use aoc_common::{parse, ParseError, Solution};
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+pub struct Day14;
+
+pub enum Instruction {
+    Mask(Mask),
+    Write { addr: u64, val: u64 }
+}
+
+/// Bits of a 36-bit mask set to 0, 1 and X
+#[derive(Default)]
+pub struct Mask {
+    zeros: u64,
+    ones: u64,
+    floating: Vec<u64>
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Model = Vec<Instruction>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_program(input)
+    }
+
+    fn part1(program: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let mut mem: HashMap<u64, u64> = HashMap::new();
+        let mut andmask = u64::MAX;
+        let mut ormask = 0u64;
+
+        for inst in program {
+            match inst {
+                Instruction::Mask(mask) => {
+                    andmask = u64::MAX - mask.zeros;
+                    ormask = mask.ones;
+                },
+                Instruction::Write { addr, val } => {
+                    let writeval = (val & andmask) | ormask;
+                    mem.insert(*addr, writeval);
+                }
+            }
+        }
+
+        Ok(mem.values().copied().sum())
+    }
+
+    fn part2(program: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let mut mem: HashMap<u64, u64> = HashMap::new();
+        let mut andmask = u64::MAX;
+        let mut ormask = 0;
+        let mut floatbits: &[u64] = &[];
+
+        for inst in program {
+            match inst {
+                Instruction::Mask(mask) => {
+                    andmask = u64::MAX - mask.floating.iter().sum::<u64>();
+                    ormask = mask.ones;
+                    floatbits = &mask.floating;
+                },
+                Instruction::Write { addr, val } => {
+                    let mut addresses = Vec::new();
+                    addresses.push((addr & andmask) | ormask);
+                    gen_addresses(floatbits, &mut addresses);
+
+                    for addr in addresses {
+                        mem.insert(addr, *val);
+                    }
+                }
+            }
+        }
+
+        Ok(mem.values().copied().sum())
+    }
+}
+
+fn load_program(input: &mut dyn BufRead) -> Result<Vec<Instruction>, ParseError> {
+    let mut program = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let mut components = line.text.split(" = ");
+
+        let target = line.next(&mut components, "'mask' or 'mem[<address>]'")?;
+        let value = line.next(&mut components, "' = '")?;
+
+        if target == "mask" {
+            let mut mask: Mask = Default::default();
+
+            if value.len() != 36 {
+                Err(line.invalid(value, "bitmask must be 36 characters"))?
+            }
+
+            let mut bit = 1 << 35;
+
+            for (i, c) in value.char_indices() {
+                match c {
+                    '0' => mask.zeros |= bit,
+                    '1' => mask.ones |= bit,
+                    'X' => mask.floating.push(bit),
+                    _ => Err(line.unexpected(&value[i..i + c.len_utf8()], "'0', '1' or 'X'"))?
+                }
+
+                bit >>= 1;
+            }
+
+            program.push(Instruction::Mask(mask));
+        } else {
+            let addr_str = match target.strip_prefix("mem[").and_then(|t| t.strip_suffix("]")) {
+                Some(addr_str) => addr_str,
+                None => Err(line.unexpected(target, "'mask' or 'mem[<address>]'"))?
+            };
+
+            let addr = line.parse::<u64>(addr_str)?;
+            let val = line.parse::<u64>(value)?;
+
+            program.push(Instruction::Write { addr, val });
+        }
+    }
+
+    Ok(program)
+}
+
+fn gen_addresses(floatbits: &[u64], addresses: &mut Vec<u64>) {
+    for f in floatbits {
+        for i in 0..addresses.len() {
+            addresses.push(addresses[i] | f)
+        }
+    }
+}
+
+#[test]
+fn test_example() {
+    let model = Day14::parse(&mut include_str!("../../example14.txt").as_bytes()).unwrap();
+    assert_eq!(165, Day14::part1(&model).unwrap());
+
+    // Part 2 has its own example as the first one floats too many bits
+    let model = Day14::parse(&mut include_str!("../../example14-2.txt").as_bytes()).unwrap();
+    assert_eq!(208, Day14::part2(&model).unwrap());
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn gen_addresses_prop(bits in prop::sample::subsequence((0..36).collect::<Vec<u32>>(), 0..=10), addr in 0..1u64 << 36) {
+        let floatbits: Vec<u64> = bits.iter().map(|b| 1 << b).collect();
+        let floatmask: u64 = floatbits.iter().sum();
+
+        let mut addresses = vec![addr & !floatmask];
+        gen_addresses(&floatbits, &mut addresses);
+
+        // Every combination of the floating bits, leaving the others alone
+        let distinct: HashSet<u64> = addresses.iter().copied().collect();
+
+        prop_assert_eq!(1 << bits.len(), addresses.len());
+        prop_assert_eq!(addresses.len(), distinct.len());
+        prop_assert!(addresses.iter().all(|a| a & !floatmask == addr & !floatmask));
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use aoc_common::{parse, ParseError, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Model = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_numbers(input)
+    }
+
+    fn part1(numbers: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        Ok(calculate(numbers.clone(), 2020)?)
+    }
+
+    fn part2(numbers: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        Ok(calculate(numbers.clone(), 30_000_000)?)
+    }
+}
+
+fn load_numbers(input: &mut dyn BufRead) -> Result<Vec<u32>, ParseError> {
+    let mut numbers: Vec<u32> = Vec::new();
+
+    let mut lines = parse::lines(input);
+
+    for line in &mut lines {
+        let line = line?;
+
+        if line.text.is_empty() {
+            continue
+        }
+
+        for num in line.text.split(',') {
+            numbers.push(line.parse::<u32>(num.trim())?);
+        }
+    }
+
+    if numbers.is_empty() {
+        Err(lines.end("starting numbers"))?
+    }
+
+    Ok(numbers)
+}
+
+fn calculate(numbers: Vec<u32>, iters: u32) -> Result<u32, String> {
+    if numbers.is_empty() {
+        Err("No starting numbers")?
+    }
+
+    if iters == 0 {
+        Err("Iterations must be at least 1")?
+    }
+
+    // Still reciting the starting numbers
+    if iters as usize <= numbers.len() {
+        return Ok(numbers[iters as usize - 1])
+    }
+
+    let mut last_ent: HashMap<u32, usize> = HashMap::new();
+
+    // The last starting number is spoken on the first turn of the loop
+    for (i, &number) in numbers[..numbers.len() - 1].iter().enumerate() {
+        last_ent.insert(number, i);
+    }
+
+    let mut last = numbers[numbers.len() - 1];
+
+    for i in numbers.len()..iters as usize {
+        let next = match last_ent.get(&last) {
+            None => 0,
+            Some(elem) => (i - *elem - 1) as u32
+        };
+
+        match last_ent.get_mut(&last) {
+            None => {
+                last_ent.insert(last, i - 1);
+                
+            },
+            Some(ent) => *ent = i - 1
+        }
+
+        last = next;
+    }
+
+    Ok(last)
+}
+
+#[test]
+fn test_calculate() {
+    assert!(calculate(vec![0,3,6], 10) == Ok(0), "0,3,6 for 10 should be 0");
+    assert!(calculate(vec![0,3,6], 2020) == Ok(436), "0,3,6 for 2020 should be 436");
+    assert!(calculate(vec![0,20,7,16,1,18,15], 2020) == Ok(1025), "0,20,7,16,1,18,15 for 2020 should be 1025");
+    assert!(calculate(vec![1,0,1], 4) == Ok(2), "1,0,1 for 4 should be 2");
+    assert!(calculate(vec![1,0,1], 2020) == Ok(221), "1,0,1 for 2020 should be 221");
+    assert!(calculate(vec![0,3,6], 1) == Ok(0), "0,3,6 for 1 should be 0");
+    assert!(calculate(vec![0,3,6], 2) == Ok(3), "0,3,6 for 2 should be 3");
+    assert!(calculate(vec![0,3,6], 3) == Ok(6), "0,3,6 for 3 should be 6");
+    assert!(calculate(vec![0,3,6], 0).is_err(), "0 iterations should fail");
+    assert!(calculate(vec![], 10).is_err(), "No starting numbers should fail");
+}
+
+#[test]
+fn test_example() {
+    let model = Day15::parse(&mut include_str!("../../example15.txt").as_bytes()).unwrap();
+
+    assert_eq!(436, Day15::part1(&model).unwrap());
+}
+
+#[test]
+#[ignore = "too slow for an unoptimised build"]
+fn test_example_part2() {
+    let model = Day15::parse(&mut include_str!("../../example15.txt").as_bytes()).unwrap();
+
+    assert_eq!(175594, Day15::part2(&model).unwrap());
+}
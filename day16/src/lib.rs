@@ -0,0 +1,244 @@
+use std::io::BufRead;
+
+use aoc_common::matching::{self, Assignment};
+use aoc_common::{parse, ParseError, Solution};
+
+pub struct Day16;
+
+#[derive(Debug)]
+pub struct MinMax {
+    min: u32,
+    max: u32
+}
+
+#[derive(Debug)]
+pub struct Ranges {
+    field: String,
+    minmax: Box<[MinMax]>,
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Model = Info;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_info(input)
+    }
+
+    fn part1((ranges, _my_ticket, other_tickets): &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let mut error: u32 = 0;
+
+        for other in other_tickets {
+            for field in other {
+                let mut ok: bool = false;
+
+                for range in ranges {
+                    for minmax in 0..2 {
+                        if *field >= range.minmax[minmax].min && *field <= range.minmax[minmax].max {
+                            ok = true;
+                            break
+                        }
+                    }
+
+                    if ok {
+                        break
+                    }
+                }
+
+                if !ok {
+                    error += field;
+                }
+            }
+        }
+
+        Ok(error)
+    }
+
+    fn part2((ranges, my_ticket, other_tickets): &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let field_count = my_ticket.len();
+
+        let fields = identify_fields(ranges, field_count, other_tickets)?;
+
+        // Total the fields beginning with 'departure '
+        let mut total = 1u64;
+
+        for i in 0..field_count {
+            if fields[i].starts_with("departure ") {
+                total *= my_ticket[i] as u64;
+            }
+        }
+
+        Ok(total)
+    }
+}
+
+/// Works out which field each ticket position holds
+fn identify_fields<'a>(ranges: &'a [Ranges], field_count: usize, other_tickets: &[Ticket]) -> Result<Vec<&'a str>, String> {
+    let valid_other = valid_other(other_tickets, ranges);
+
+    // Fields which fit the value at each position on every valid ticket
+    let candidates: Vec<Vec<usize>> = (0..field_count).map(|i| {
+        (0..ranges.len()).filter(|&f| {
+            valid_other.iter().all(|t| t.get(i).is_some_and(|&val| in_range(&ranges[f], val)))
+        }).collect()
+    }).collect();
+
+    let field_name = |f: usize| &ranges[f].field[..];
+
+    match matching::assign(&candidates, ranges.len()) {
+        Assignment::Unique(fields) => Ok(fields.into_iter().map(field_name).collect()),
+        Assignment::Ambiguous { options, .. } => {
+            let alternatives: Vec<String> = options.iter().enumerate()
+                .filter(|(_, fields)| fields.len() > 1)
+                .map(|(i, fields)| {
+                    let names: Vec<&str> = fields.iter().map(|&f| field_name(f)).collect();
+                    format!("position {} could be {}", i + 1, names.join(" or "))
+                }).collect();
+
+            Err(format!("Ticket fields are ambiguous: {}", alternatives.join(", ")))
+        }
+        Assignment::Impossible(fields) => {
+            let positions: Vec<String> = fields.iter().enumerate()
+                .filter(|(_, f)| f.is_none())
+                .map(|(i, _)| (i + 1).to_string())
+                .collect();
+
+            Err(format!("No field fits ticket positions {}", positions.join(", ")))
+        }
+    }
+}
+
+fn in_range(range: &Ranges, val: u32) -> bool {
+    range.minmax.iter().any(|mm| val >= mm.min && val <= mm.max)
+}
+
+fn valid_other<'a>(other_tickets: &'a [Ticket], ranges: &[Ranges]) -> Vec<&'a Ticket> {
+    let valid = other_tickets.iter().filter(|&t| {
+        for field in t {
+            let mut field_ok: bool = false;
+
+            for range in ranges {
+                for minmax in 0..2 {
+                    if *field >= range.minmax[minmax].min && *field <= range.minmax[minmax].max {
+                        field_ok = true;
+                        break
+                    }
+                }
+
+                if field_ok {
+                    break
+                }
+            }
+
+            if !field_ok {
+                return false
+            }
+        }
+
+        true
+    }).collect::<Vec<&Ticket>>();
+
+    valid.to_vec()
+}
+
+enum LoadStage {
+    Ranges,
+    MyTicket,
+    OtherTickets
+}
+
+type Ticket = Vec<u32>;
+pub type Info = (Vec<Ranges>, Ticket, Vec<Ticket>);
+
+fn load_info(input: &mut dyn BufRead) -> Result<Info, ParseError> {
+    let mut ranges: Vec<Ranges> = Vec::new();
+    let mut my_ticket: Vec<u32> = Vec::new();
+    let mut other_tickets: Vec<Vec<u32>> = Vec::new();
+
+    let mut load_stage: LoadStage = LoadStage::Ranges;
+    
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        match load_stage {
+            LoadStage::Ranges => {
+                if line.text.is_empty() {
+                    load_stage = LoadStage::MyTicket
+                } else {
+                    let mut field_split = line.text.split(":");
+                    let field = line.next(&mut field_split, "field name")?;
+
+                    let mut range = Ranges {
+                        field: field.to_string(),
+                        minmax: Box::new([
+                            MinMax {
+                                min: 0,
+                                max: 0
+                            },
+                            MinMax {
+                                min: 0,
+                                max: 0
+                            },
+                        ])
+                    };
+
+                    let mut or_split = line.next(&mut field_split, "':'")?.trim().split(" or ");
+
+                    for minmax in range.minmax.iter_mut() {
+                        let or_term = line.next(&mut or_split, "range")?;
+                        let mut ors = or_term.split("-");
+                        minmax.min = line.parse(line.next(&mut ors, "range minimum")?)?;
+                        minmax.max = line.parse(line.next(&mut ors, "range maximum")?)?;
+                    }
+
+                    if let Some(extra) = or_split.next() {
+                        Err(line.unexpected(extra, "end of line"))?
+                    }
+
+                    ranges.push(range)
+                }
+            },
+            LoadStage::MyTicket => {
+                if line.text.is_empty() {
+                    load_stage = LoadStage::OtherTickets
+                } else if line.text != "your ticket:" {
+                    for elem in line.text.split(",") {
+                        my_ticket.push(line.parse(elem)?)
+                    }
+                }
+            },
+            LoadStage::OtherTickets => {
+                if line.text != "nearby tickets:" {
+                    other_tickets.push(line.text.split(",").map(|e| line.parse::<u32>(e)).collect::<Result<_, _>>()?);
+                }
+            }
+        }
+    }
+
+    Ok((ranges, my_ticket, other_tickets))
+}
+
+#[test]
+fn test_example() {
+    let model = Day16::parse(&mut include_str!("../../example16.txt").as_bytes()).unwrap();
+    assert_eq!(71, Day16::part1(&model).unwrap());
+
+    // The part 2 example has no departure fields, so check the field order instead
+    let (ranges, my_ticket, other_tickets) = Day16::parse(&mut include_str!("../../example16-2.txt").as_bytes()).unwrap();
+    let fields = identify_fields(&ranges, my_ticket.len(), &other_tickets);
+    assert_eq!(Ok(vec!["row", "class", "seat"]), fields);
+}
+
+#[test]
+fn test_identify_errors() {
+    let (ranges, my_ticket, other_tickets) = Day16::parse(&mut "a: 1-5 or 7-9\nb: 1-5 or 7-9\n\nyour ticket:\n1,2\n\nnearby tickets:\n3,4\n".as_bytes()).unwrap();
+    assert_eq!(Err("Ticket fields are ambiguous: position 1 could be a or b, position 2 could be a or b".to_string()),
+        identify_fields(&ranges, my_ticket.len(), &other_tickets));
+
+    let (ranges, my_ticket, other_tickets) = Day16::parse(&mut "a: 1-5 or 7-9\nb: 1-1 or 3-3\n\nyour ticket:\n1,2\n\nnearby tickets:\n7,8\n".as_bytes()).unwrap();
+    assert_eq!(Err("No field fits ticket positions 2".to_string()),
+        identify_fields(&ranges, my_ticket.len(), &other_tickets));
+}
@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use aoc_common::ca::{Automaton, NDim, Rule};
+use aoc_common::{debug, debug_print, diag, grid, Grid, ParseError, Solution};
+
+pub struct Day17;
+
+/// Coordinates of the active cubes in the initial slice
+type Board = Vec<(i64, i64)>;
+
+/// Names of the first dimensions
+const AXES: [&str; 4] = ["x", "y", "z", "w"];
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Model = Board;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_board(input)
+    }
+
+    fn part1(board: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        Ok(run_cycles::<3>(board, 6))
+    }
+
+    fn part2(board: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        Ok(run_cycles::<4>(board, 6))
+    }
+}
+
+/// Runs the cubes in N dimensions with the board as the slice at 0 in the higher dimensions
+fn run_cycles<const N: usize>(board: &[(i64, i64)], cycles: usize) -> usize {
+    let cubes = board.iter().map(|&(x, y)| {
+        let mut coord = [0; N];
+        coord[0] = x;
+        coord[1] = y;
+        coord
+    });
+
+    let mut automaton = Automaton::new(NDim::<N>::new(), Rule::new(&[3], &[2, 3]), cubes);
+
+    dump_state("Initial", automaton.live());
+
+    for iter in 0..cycles {
+        automaton.step();
+
+        dump_state(&format!("After cycle {}", iter + 1), automaton.live());
+    }
+
+    let active = automaton.live().len();
+
+    debug!("{} cubes active", active);
+
+    active
+}
+
+fn axis(dim: usize) -> String {
+    AXES.get(dim).map_or_else(|| format!("d{}", dim), |a| a.to_string())
+}
+
+fn dump_state<const N: usize>(desc: &str, cubes: &HashSet<[i64; N]>) {
+    if diag::quiet() || cubes.is_empty() {
+        return
+    }
+
+    debug!("{}:", desc);
+    debug!();
+
+    let mut min = [i64::MAX; N];
+    let mut max = [i64::MIN; N];
+
+    for cube in cubes {
+        for dim in 0..N {
+            min[dim] = min[dim].min(cube[dim]);
+            max[dim] = max[dim].max(cube[dim]);
+        }
+    }
+
+    // Position of each x/y slice in the higher dimensions
+    let mut slices: Vec<Vec<i64>> = vec![Vec::new()];
+
+    for dim in 2..N {
+        slices = slices.into_iter().flat_map(|slice| {
+            (min[dim]..=max[dim]).map(move |v| {
+                let mut slice = slice.clone();
+                slice.push(v);
+                slice
+            })
+        }).collect();
+    }
+
+    for slice in slices {
+        let pos: Vec<String> = slice.iter().enumerate().map(|(i, v)| format!("{}={}", axis(i + 2), v)).collect();
+
+        debug!("{} (@ x={}, y={})", pos.join(" "), min[0], min[1]);
+
+        let width = (max[0] - min[0] + 1) as usize;
+        let height = (max[1] - min[1] + 1) as usize;
+
+        let plane = Grid::from_fn(width, height, |x, y| {
+            let mut coord = [0; N];
+            coord[0] = min[0] + x as i64;
+            coord[1] = min[1] + y as i64;
+            coord[2..].copy_from_slice(&slice);
+
+            cubes.contains(&coord)
+        });
+
+        debug_print!("{}", plane.render(grid::to_hash_dot));
+        debug!();
+    }
+}
+
+fn load_board(input: &mut dyn BufRead) -> Result<Board, ParseError> {
+    let slice = grid::parse(input, grid::hash_dot, "'.' or '#'")?;
+
+    Ok(slice.iter()
+        .filter(|(_, &active)| active)
+        .map(|((x, y), _)| (x as i64, y as i64))
+        .collect())
+}
+
+#[test]
+fn test_example() {
+    let model = Day17::parse(&mut include_str!("../../example17.txt").as_bytes()).unwrap();
+
+    assert_eq!(112, Day17::part1(&model).unwrap());
+    assert_eq!(848, Day17::part2(&model).unwrap());
+}
@@ -1,40 +1,67 @@
-use std::fs;
-use std::io::{self, BufRead};
+use std::io::BufRead;
 use std::fmt;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let expressions = load_expressions()?;
+use aoc_common::{debug, parse, warn, Line, ParseError, Solution};
 
-    let mut total: u64 = 0;
+pub struct Day18;
 
-    for e in expressions {
-        let te = transform_expression(&e);
+impl Solution for Day18 {
+    const DAY: u8 = 18;
 
-        let result = evaluate_expression(&te);
+    type Model = Vec<Expression>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-        println!("{} => {} = {}", &e, &te, result);
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_expressions(input)
+    }
+
+    fn part1(expressions: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        // Operators have equal precedence
+        let mut total: u64 = 0;
+
+        for e in expressions {
+            let result = evaluate_expression(e);
+
+            debug!("{} = {}", e, result);
 
-        total += result;
+            total += result;
+        }
+
+        Ok(total)
     }
 
-    println!("Total: {}", total);
+    fn part2(expressions: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        // Addition has higher precedence than multiplication
+        let mut total: u64 = 0;
 
-    Ok(())
-}
+        for e in expressions {
+            let te = transform_expression(e);
 
-fn load_expressions() -> Result<Vec<Expression>, Box<dyn std::error::Error>> {
-    // Open the file read only
-    let input = fs::File::open("../input18.txt")?;
+            let result = evaluate_expression(&te);
 
-    // Create a buffered reader on the file
-    let inputbuf = io::BufReader::new(input);
+            debug!("{} => {} = {}", e, &te, result);
 
+            total += result;
+        }
+
+        Ok(total)
+    }
+}
+
+fn load_expressions(input: &mut dyn BufRead) -> Result<Vec<Expression>, ParseError> {
     let mut expressions = Vec::new();
 
-    for line_result in inputbuf.lines() {
-        let line: Vec<_> = line_result?.chars().collect();
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let mut pos = 0;
+        let expression = parse_expression(&line, &mut pos)?;
 
-        let expression = parse_expression(&line, &mut 0)?;
+        if pos < line.text.len() {
+            // Stopped on a closing bracket with no opening bracket
+            Err(line.unexpected(&line.text[pos..pos + 1], "operator, integer or '('"))?
+        }
 
         expressions.push(expression);
     }
@@ -42,12 +69,10 @@ fn load_expressions() -> Result<Vec<Expression>, Box<dyn std::error::Error>> {
     Ok(expressions)
 }
 
-fn parse_expression(line: &Vec<char>, pos: &mut usize) -> Result<Expression, Box<dyn std::error::Error>> {
+fn parse_expression(line: &Line, pos: &mut usize) -> Result<Expression, ParseError> {
     let mut expression: Expression = Default::default();
 
-    while *pos < line.len() {
-        let c = line[*pos];
-
+    while let Some(c) = line.text[*pos..].chars().next() {
         match c {
             ' ' => {}, // Skip whitespace
             '*' | '+' => {
@@ -63,11 +88,11 @@ fn parse_expression(line: &Vec<char>, pos: &mut usize) -> Result<Expression, Box
                 break
             }
             _ => {
-                if c.is_digit(10) {
+                if c.is_ascii_digit() {
                     // Start of an integer
                     expression.terms.push(Term::Integer(parse_integer(line, pos)?));
                 } else {
-                    Err(format!("Unexpected character {}", c))?;
+                    Err(line.unexpected(&line.text[*pos..*pos + c.len_utf8()], "operator, integer or bracket"))?;
                 }
             }
         }
@@ -78,36 +103,26 @@ fn parse_expression(line: &Vec<char>, pos: &mut usize) -> Result<Expression, Box
     Ok(expression)
 }
 
-fn parse_bracket(line: &Vec<char>, pos: &mut usize) -> Result<Expression, Box<dyn std::error::Error>> {
-    assert!(line[*pos] == '(', "Expecting bracket");
+fn parse_bracket(line: &Line, pos: &mut usize) -> Result<Expression, ParseError> {
+    // Skip opening bracket
     *pos += 1;
 
     let expression = parse_expression(line, pos)?;
 
+    if *pos >= line.text.len() {
+        Err(line.missing("')'"))?
+    }
+
     Ok(expression)
 }
 
-fn parse_integer(line: &Vec<char>, pos: &mut usize) -> Result<u32, Box<dyn std::error::Error>> {
-    let mut scan_pos = *pos;
-
-    loop {
-        scan_pos += 1;
-
-        if scan_pos >= line.len() {
-            break
-        }
-
-        if !line[scan_pos].is_digit(10) {
-            break
-        }
-    }
+fn parse_integer(line: &Line, pos: &mut usize) -> Result<u32, ParseError> {
+    let digits = line.text[*pos..].bytes().take_while(|b| b.is_ascii_digit()).count();
 
-    let num_str: String = line[*pos..scan_pos].iter().collect();
+    let number = line.parse(&line.text[*pos..*pos + digits])?;
 
-    scan_pos -= 1;
-    *pos = scan_pos;
-
-    let number = num_str.parse()?;
+    // Leave position on the last digit
+    *pos += digits - 1;
 
     Ok(number)
 }
@@ -149,7 +164,7 @@ fn transform_expression(e: &Expression) -> Expression {
                 last_op = *c;
             }
             Term::Expression(e) => {
-                let te = Term::Expression(transform_expression(&e));
+                let te = Term::Expression(transform_expression(e));
                 lhs = push_op(lhs, last_op, te);
             }
         }
@@ -170,7 +185,7 @@ fn evaluate_expression(e: &Expression) -> u64 {
             '+' => result + value,
             '*' => result * value,
             _ => {
-                println!("Unrecognised operator {}", op);
+                warn(format!("Unrecognised operator {}", op));
                 result
             }
         };
@@ -194,7 +209,7 @@ fn evaluate_expression(e: &Expression) -> u64 {
 }
 
 #[derive(Default, Debug)]
-struct Expression {
+pub struct Expression {
     terms: Vec<Term>
 }
 
@@ -209,7 +224,7 @@ impl fmt::Display for Expression {
 }
 
 #[derive(Debug)]
-enum Term {
+pub enum Term {
     Integer(u32),
     Operator(char),
     Expression(Expression),
@@ -226,3 +241,22 @@ impl fmt::Display for Term {
         Ok(())
     }
 }
+
+#[test]
+fn test_example() {
+    // Each expression's published values, then the total of them all
+    for (expr, p1, p2) in &[("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+                            ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+                            ("2 * 3 + (4 * 5)", 26, 46),
+                            ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+                            ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060),
+                            ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 13632, 23340)] {
+        let model = Day18::parse(&mut expr.as_bytes()).unwrap();
+        assert_eq!(*p1, Day18::part1(&model).unwrap(), "{}", expr);
+        assert_eq!(*p2, Day18::part2(&model).unwrap(), "{}", expr);
+    }
+
+    let model = Day18::parse(&mut include_str!("../../example18.txt").as_bytes()).unwrap();
+    assert_eq!(26457, Day18::part1(&model).unwrap());
+    assert_eq!(694173, Day18::part2(&model).unwrap());
+}
@@ -0,0 +1,304 @@
+use std::io::BufRead;
+use std::fmt;
+use std::collections::HashMap;
+
+use aoc_common::{debug, parse, stat, Line, ParseError, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Model = (Rules, Vec<Message>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_input(input)
+    }
+
+    fn part1((rules, messages): &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        Ok(count_matches(rules, messages)?)
+    }
+
+    fn part2((rules, messages): &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let mut rules = rules.clone();
+
+        // The looping rules refer to these
+        for r in &[42, 31] {
+            if !rules.contains_key(r) {
+                Err(format!("Rule {} is missing", r))?
+            }
+        }
+
+        // Replace rules 8 and 11 with the looping versions
+        load_rule(&mut rules, &Line::new(8, "8: 42 | 42 8"))?;
+        load_rule(&mut rules, &Line::new(11, "11: 42 31 | 42 11 31"))?;
+
+        Ok(count_matches(&rules, messages)?)
+    }
+}
+
+fn count_matches(rules: &Rules, messages: &[Message]) -> Result<usize, String> {
+    let mut matched = 0;
+
+    for m in messages {
+        if match_message(m, rules)? {
+            matched += 1
+        }
+    }
+
+    debug!("{} messages matched (out of {})", matched, messages.len());
+    stat("matched", matched);
+    stat("messages", messages.len());
+
+    Ok(matched)
+}
+
+type RuleNum = u8;
+pub type Rules = HashMap<RuleNum, Rule>;
+
+#[derive(Clone)]
+pub enum Rule {
+    Empty,
+    Ref(RuleNum),
+    Chain(RuleNum, Box<Rule>),
+    Or(Box<Rule>, Box<Rule>),
+    Char(char)
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rule::Empty => write!(f, "(empty)"),
+            Rule::Ref(r) => write!(f, "{}", r),
+            Rule::Chain(rn, br) => write!(f, "{} {}", rn, *br),
+            Rule::Or(br1, br2) => write!(f, "{} | {}", *br1, *br2),
+            Rule::Char(c) => write!(f, "\"{}\"", c),
+        }
+    }
+}
+
+enum LoadStage {
+    Rules,
+    Messages
+}
+
+pub type Message = Vec<char>;
+
+fn load_input(input: &mut dyn BufRead) -> Result<(Rules, Vec<Message>), ParseError> {
+    let mut rules = HashMap::new();
+    let mut rule_lines = Vec::new();
+    let mut separator = None;
+    let mut messages = Vec::new();
+
+    let mut load_stage = LoadStage::Rules;
+
+    let mut lines = parse::lines(input);
+
+    for line_result in &mut lines {
+        let line = line_result?;
+
+        match load_stage {
+            LoadStage::Rules => {
+                if line.text.is_empty() {
+                    load_stage = LoadStage::Messages;
+                    separator = Some(line);
+                } else {
+                    load_rule(&mut rules, &line)?;
+                    rule_lines.push(line);
+                }
+            },
+            LoadStage::Messages => {
+                messages.push(line.text.chars().collect());
+            }
+        }
+    }
+
+    // Every rule referred to must exist, starting with rule 0
+    if !rules.contains_key(&0) {
+        match separator {
+            Some(line) => Err(line.missing("rule 0"))?,
+            None => Err(lines.end("rule 0"))?
+        }
+    }
+
+    for line in &rule_lines {
+        for term in line.text.split_whitespace().skip(1) {
+            if let Ok(r) = term.parse::<RuleNum>() {
+                if !rules.contains_key(&r) {
+                    Err(line.invalid(term, "no such rule"))?
+                }
+            }
+        }
+    }
+
+    Ok((rules, messages))
+}
+
+fn load_rule(rules: &mut Rules, line: &Line) -> Result<(), ParseError> {
+    let mut split = line.text.split_whitespace();
+
+    let rulenostr = line.next(&mut split, "rule number")?;
+    let ruleno = match rulenostr.strip_suffix(":") {
+        Some(numstr) => line.parse::<RuleNum>(numstr)?,
+        None => Err(line.unexpected(rulenostr, "rule number followed by ':'"))?
+    };
+
+    let mut rule = Rule::Empty;
+
+    for term in split {
+        if term == "|" {
+            rule = match rule {
+                Rule::Ref(rn) => {
+                    Rule::Or(Box::new(Rule::Ref(rn)), Box::new(Rule::Empty))
+                },
+                Rule::Chain(rn, br) => {
+                    Rule::Or(Box::new(Rule::Chain(rn, br)), Box::new(Rule::Empty))
+                },
+                Rule::Char(c) => {
+                    Rule::Or(Box::new(Rule::Char(c)), Box::new(Rule::Empty))
+                },
+                _ => Err(line.unexpected(term, "rule reference or character"))?
+            };
+
+        } else if let Ok(r) = term.parse::<RuleNum>() {
+            rule = match rule {
+                Rule::Or(br1, br2) => {
+                    match chain_ref(*br2, r) {
+                        Some(br2) => Rule::Or(br1, Box::new(br2)),
+                        None => Err(line.unexpected(term, "'|' or end of line"))?
+                    }
+                },
+                rule => {
+                    match chain_ref(rule, r) {
+                        Some(rule) => rule,
+                        None => Err(line.unexpected(term, "'|' or end of line"))?
+                    }
+                }
+            };
+
+        } else if term.starts_with('\"') {
+            let mut chars = term.chars();
+
+            rule = match (rule, chars.next(), chars.next(), chars.next(), chars.next()) {
+                (Rule::Empty, Some('"'), Some(c), Some('"'), None) => {
+                    Rule::Char(c)
+                },
+                (Rule::Empty, ..) => Err(line.unexpected(term, "quoted character"))?,
+                _ => Err(line.unexpected(term, "end of line"))?
+            };
+
+        }
+        else {
+            Err(line.unexpected(term, "rule reference, '|' or quoted character"))?
+
+        }
+    }
+
+    match &rule {
+        Rule::Empty => Err(line.missing("rule reference or quoted character"))?,
+        Rule::Or(_, br2) if matches!(**br2, Rule::Empty) => Err(line.missing("rule reference"))?,
+        _ => {}
+    }
+
+    rules.insert(ruleno, rule);
+
+    Ok(())
+}
+
+/// Appends a rule reference to a sequence of references
+fn chain_ref(rule: Rule, r: RuleNum) -> Option<Rule> {
+    match rule {
+        Rule::Empty => Some(Rule::Ref(r)),
+        Rule::Ref(rn) => Some(Rule::Chain(rn, Box::new(Rule::Ref(r)))),
+        Rule::Chain(rn, br) => Some(Rule::Chain(rn, Box::new(chain_ref(*br, r)?))),
+        _ => None
+    }
+}
+
+fn match_message(message: &[char], rules: &Rules) -> Result<bool, String> {
+    let mut ok = false;
+
+    let rule = get_rule(rules, 0)?;
+    
+    let pos = match_rule(message, rules, &[0], rule)?;
+
+    let valid = pos.iter().filter(|&&p| {p == message.len()}).count();
+
+    if valid > 0 {
+        ok = true
+    }
+
+    Ok(ok)
+}
+
+fn get_rule(rules: &Rules, r: RuleNum) -> Result<&Rule, String> {
+    rules.get(&r).ok_or_else(|| format!("Rule {} is missing", r))
+}
+
+fn match_rule(message: &[char], rules: &Rules, posvec: &[usize], rule: &Rule) -> Result<Vec<usize>, String> {
+    let results = match rule {
+        Rule::Ref(r) => {
+            let rule = get_rule(rules, *r)?;
+            match_rule(message, rules, posvec, rule)?
+        },
+        Rule::Chain(r, br) => {
+            let rule = get_rule(rules, *r)?;
+
+            let mut next_pos = match_rule(message, rules, posvec, rule)?;
+
+            if !next_pos.is_empty() {
+                next_pos = match_rule(message, rules, &next_pos, br)?
+            }
+
+            next_pos
+        },
+        Rule::Or(br1, br2) => {
+            let mut or = match_rule(message, rules, posvec, br1)?;
+            or.append(&mut match_rule(message, rules, posvec, br2)?);
+            or
+        },
+        Rule::Char(c) => {
+            posvec.iter().filter_map(|&pos| {
+                if pos < message.len() {
+                    if message[pos] == *c {
+                        Some(pos + 1)
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            }).collect()
+        },
+        Rule::Empty => Err("Unable to match an empty rule")?
+    };
+
+    Ok(results)
+}
+
+#[test]
+fn test_example() {
+    let model = Day19::parse(&mut include_str!("../../example19.txt").as_bytes()).unwrap();
+    assert_eq!(2, Day19::part1(&model).unwrap());
+
+    let model = Day19::parse(&mut include_str!("../../example19-2.txt").as_bytes()).unwrap();
+    assert_eq!(3, Day19::part1(&model).unwrap());
+    assert_eq!(12, Day19::part2(&model).unwrap());
+}
+
+#[test]
+fn missing_rule_test() {
+    let err = |input: &str| Day19::parse(&mut input.as_bytes()).err().map(|e| e.to_string());
+
+    assert_eq!(Some("line 1 column 6: invalid '2': no such rule".to_string()), err("0: 1 2\n1: \"a\"\n\nab"));
+    assert_eq!(Some("line 2 column 10: invalid '3': no such rule".to_string()), err("0: 1\n1: \"a\" | 3\n\na"));
+    assert_eq!(Some("line 2 column 1: missing rule 0".to_string()), err("1: \"a\"\n\na"));
+    assert_eq!(Some("line 2 column 1: input ended, expecting rule 0".to_string()), err("1: \"a\""));
+
+    // Part 2 needs rules 42 and 31
+    let model = Day19::parse(&mut "0: 1\n1: \"a\"\n\na".as_bytes()).unwrap();
+    assert_eq!(1, Day19::part1(&model).unwrap());
+    assert_eq!("Rule 42 is missing", Day19::part2(&model).unwrap_err().to_string());
+}
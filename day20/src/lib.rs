@@ -1,9 +1,14 @@
-use std::fs;
-use std::io::{self, BufRead};
-use std::str;
+use std::io::BufRead;
 use std::cmp;
 use std::collections::HashMap;
 
+use aoc_common::{debug, debug_print, parse, stat, Grid, Line, ParseError, Solution};
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+pub struct Day20;
+
 const TILE_DIM: usize = 10;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -16,8 +21,8 @@ enum Edge {
 
 type TileNum = u16;
 
-#[derive(Default)]
-struct Tile {
+#[derive(Debug, Default, Clone)]
+pub struct Tile {
     tile_num: TileNum,          // Tile number
     tile_data: [u16; TILE_DIM], // Raw tile data
     edge_norm: [u16; 4],        // Normalised edge (lowest of forward and reverse bits)
@@ -26,39 +31,60 @@ struct Tile {
     south: TileNum              // The tile to the south
 }
 
-type TileMap = HashMap<TileNum, Tile>;
+pub type TileMap = HashMap<TileNum, Tile>;
 
 type EdgeMap = HashMap<u16, Vec<TileNum>>;
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut tiles = load_tiles()?;
+impl Solution for Day20 {
+    const DAY: u8 = 20;
 
-    let edges = build_edge_lookup(&tiles);
+    type Model = TileMap;
+    type Answer1 = u64;
+    type Answer2 = usize;
 
-    let corner = arrange_tiles(&mut tiles, &edges);
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_tiles(input)
+    }
 
-    let mut map = build_map(&tiles, corner);
+    fn part1(tiles: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let edges = build_edge_lookup(tiles);
 
-    let found = find_monsters(&mut map);
+        let mut tile_nums: Vec<_> = tiles.keys().collect();
+        tile_nums.sort();
 
-    println!("Found {} monsters", found);
+        let mut result: u64 = 1;
 
-    print_map(&map);
+        for tile_num in tile_nums {
+            if get_outside_edges(&tiles[tile_num], &edges).len() == 2 {
+                debug!("Tile {} is a corner", tile_num);
+                result *= *tile_num as u64;
+            }
+        }
+
+        Ok(result)
+    }
 
-    let roughness = count_hash(&map);
+    fn part2(tiles: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let mut tiles = tiles.clone();
 
-    println!("Roughness is {}", roughness);
+        let edges = build_edge_lookup(&tiles);
 
-    Ok(())
-}
+        let corner = arrange_tiles(&mut tiles, &edges);
 
-fn load_tiles() -> Result<TileMap, Box<dyn std::error::Error>> {
-    // Open the file read only
-    let input = fs::File::open("../input20.txt")?;
+        let map = build_map(&tiles, corner);
 
-    // Create a buffered reader on the file
-    let inputbuf = io::BufReader::new(input);
+        let (found, map) = find_monsters(&map);
 
+        debug!("Found {} monsters", found);
+        stat("monsters", found);
+
+        print_map(&map);
+
+        Ok(count_hash(&map))
+    }
+}
+
+fn load_tiles(input: &mut dyn BufRead) -> Result<TileMap, ParseError> {
     let mut tiles = HashMap::new();
 
     enum LoadStage {
@@ -72,14 +98,27 @@ fn load_tiles() -> Result<TileMap, Box<dyn std::error::Error>> {
     let mut tile: Tile = Default::default();
     let mut data_line: usize = 0;
 
-    for line_result in inputbuf.lines() {
+    let mut lines = parse::lines(input);
+
+    for line_result in &mut lines {
         let line = line_result?;
 
         match load_stage {
             LoadStage::TileNum => {
-                tile.tile_num = line.split_whitespace().nth(1).unwrap()
-                    .split(":").next().unwrap()
-                    .parse::<u16>().unwrap();
+                let mut split = line.text.split_whitespace();
+
+                let title = line.next(&mut split, "'Tile'")?;
+
+                if title != "Tile" {
+                    Err(line.unexpected(title, "'Tile'"))?
+                }
+
+                let numstr = line.next(&mut split, "tile number")?;
+
+                tile.tile_num = match numstr.strip_suffix(":") {
+                    Some(numstr) => line.parse::<u16>(numstr)?,
+                    None => Err(line.unexpected(numstr, "tile number followed by ':'"))?
+                };
 
                 load_stage = LoadStage::TileData;
                 data_line = 0;
@@ -100,8 +139,8 @@ fn load_tiles() -> Result<TileMap, Box<dyn std::error::Error>> {
                 }
             }
             LoadStage::Blank => {
-                if !line.is_empty() {
-                    Err("Expecting blank line")?
+                if !line.text.is_empty() {
+                    Err(line.unexpected(&line.text, "blank line"))?
                 }
 
                 load_stage = LoadStage::TileNum
@@ -109,22 +148,30 @@ fn load_tiles() -> Result<TileMap, Box<dyn std::error::Error>> {
         }
     }
 
+    if let LoadStage::TileData = load_stage {
+        Err(lines.end("tile data"))?
+    }
+
     Ok(tiles)
 }
 
-fn string_to_int(string: &str) -> Result<u16, Box<dyn std::error::Error>> {
+fn string_to_int(line: &Line) -> Result<u16, ParseError> {
     let mut result: u16 = 0;
 
+    if line.text.chars().count() != TILE_DIM {
+        Err(line.invalid(&line.text, &format!("tile rows must be {} characters", TILE_DIM)))?
+    }
+
     let mut bit = 1 << (TILE_DIM - 1);
 
-    for c in string.chars() {
+    for (i, c) in line.text.char_indices() {
         match c {
             '.' => {},
             '#' => {
                 result += bit;
             },
             _ => {
-                Err(format!("Invalid character {}", c))?
+                Err(line.unexpected(&line.text[i..i + c.len_utf8()], "'.' or '#'"))?
             }
         }
 
@@ -222,9 +269,7 @@ fn build_edge_lookup(tiles: &TileMap) -> EdgeMap {
     let mut add = |edge_val: u16, tile: &Tile| {
         match map.get_mut(&edge_val) {
             None => {
-                let mut coll: Vec<u16> = Vec::new();
-                coll.push(tile.tile_num);
-                map.insert(edge_val, coll);
+                map.insert(edge_val, vec![tile.tile_num]);
             }
             Some(coll) => {
                 coll.push(tile.tile_num);
@@ -232,7 +277,7 @@ fn build_edge_lookup(tiles: &TileMap) -> EdgeMap {
         };
     };
 
-    for (_tile_num, tile) in tiles {
+    for tile in tiles.values() {
         add(tile.edge_norm[Edge::North as usize], tile);
         add(tile.edge_norm[Edge::East as usize], tile);
         add(tile.edge_norm[Edge::South as usize], tile);
@@ -283,8 +328,8 @@ fn arrange_tiles(tiles: &mut TileMap, edges: &EdgeMap) -> TileNum {
         flip_horiz(corner_tile);
     }
 
-    let test_orient = get_outside_edges(corner_tile, &edges);
-    assert!(test_orient == vec![Edge::North, Edge::West], format!("Orientation failed - {:?}", test_orient));
+    let test_orient = get_outside_edges(corner_tile, edges);
+    assert!(test_orient == vec![Edge::North, Edge::West], "Orientation failed - {:?}", test_orient);
 
     // Set current left tile number
     let mut left_tile_num = corner_tile_num;
@@ -308,20 +353,20 @@ fn find_corner(tiles: &TileMap, edges: &EdgeMap) -> Option<(u16, Vec<Edge>)> {
 
     // Find a corner
     for (&tile_num, tile) in tiles {
-        let outside = get_outside_edges(&tile, &edges);
+        let outside = get_outside_edges(tile, edges);
 
         if outside.len() == 2 {
             corners.push(tile_num);
         }
     }
 
-    if corners.len() == 0 {
+    if corners.is_empty() {
         None
     } else{
         corners.sort();
         let first = corners[0];
         let tile = tiles.get(&first).unwrap();
-        let outside = get_outside_edges(&tile, &edges);
+        let outside = get_outside_edges(tile, edges);
         Some((first, outside))
     }
 }
@@ -348,13 +393,13 @@ fn walk_east(tile_num: TileNum, tiles: &mut TileMap, edges: &EdgeMap) {
     let mut cur_east: u16;
     let mut cur_east_norm: u16;
 
-    print!("East from {}", tile_num);
+    debug_print!("East from {}", tile_num);
 
     loop {
         // Find out which tile is to the east
         match tiles.get(&cur_tile_num) {
             Some(cur_tile) => {
-                match find_tile(&cur_tile, Edge::East, &edges) {
+                match find_tile(cur_tile, Edge::East, edges) {
                     Some(t) => {
                         cur_east_tile_num = t;
                         cur_east = cur_tile.edge[Edge::East as usize];
@@ -367,25 +412,25 @@ fn walk_east(tile_num: TileNum, tiles: &mut TileMap, edges: &EdgeMap) {
         };
 
         // Update current tile
-        if let Some(mut cur_tile) = tiles.get_mut(&cur_tile_num) {
+        if let Some(cur_tile) = tiles.get_mut(&cur_tile_num) {
             cur_tile.east = cur_east_tile_num;
         } else {
             panic!("Unable to get current tile");
         }
 
         // Update tile to the east
-        if let Some(mut east_tile) = tiles.get_mut(&cur_east_tile_num) {
+        if let Some(east_tile) = tiles.get_mut(&cur_east_tile_num) {
             // Move matching side to the east side of this tile
-            match find_side_norm(&east_tile, cur_east_norm) {
-                Edge::North => rotate_ccw(&mut east_tile),
-                Edge::East => flip_horiz(&mut east_tile),
-                Edge::South => rotate_cw(&mut east_tile),
+            match find_side_norm(east_tile, cur_east_norm) {
+                Edge::North => rotate_ccw(east_tile),
+                Edge::East => flip_horiz(east_tile),
+                Edge::South => rotate_cw(east_tile),
                 Edge::West => {}
             }
 
             // Flip vertically if necessary
             if cur_east != east_tile.edge[Edge::West as usize] {
-                flip_vert(&mut east_tile);
+                flip_vert(east_tile);
             }
             assert!(cur_east == east_tile.edge[Edge::West as usize], "East sides don't match");
         } else {
@@ -395,10 +440,10 @@ fn walk_east(tile_num: TileNum, tiles: &mut TileMap, edges: &EdgeMap) {
         // Move to the east tile
         cur_tile_num = cur_east_tile_num;
 
-        print!(" -> {}", cur_tile_num);
+        debug_print!(" -> {}", cur_tile_num);
     }
 
-    println!("");
+    debug!();
 }
 
 fn step_south(tile_num: TileNum, tiles: &mut TileMap, edges: &EdgeMap) -> Option<TileNum> {
@@ -409,7 +454,7 @@ fn step_south(tile_num: TileNum, tiles: &mut TileMap, edges: &EdgeMap) -> Option
     // Find out which tile is to the south
     match tiles.get(&tile_num) {
         Some(cur_tile) => {
-            match find_tile(&cur_tile, Edge::South, &edges) {
+            match find_tile(cur_tile, Edge::South, edges) {
                 Some(t) => {
                     south_tile_num = t;
                     south = cur_tile.edge[Edge::South as usize];
@@ -422,23 +467,23 @@ fn step_south(tile_num: TileNum, tiles: &mut TileMap, edges: &EdgeMap) -> Option
     };
 
     // Update current tile
-    if let Some(mut cur_tile) = tiles.get_mut(&tile_num) {
+    if let Some(cur_tile) = tiles.get_mut(&tile_num) {
         cur_tile.south = south_tile_num;
     };
 
     // Update tile to the south
-    if let Some(mut south_tile) = tiles.get_mut(&south_tile_num) {
+    if let Some(south_tile) = tiles.get_mut(&south_tile_num) {
         // Move matching side to the south side of this tile
-        match find_side_norm(&south_tile, south_norm) {
+        match find_side_norm(south_tile, south_norm) {
             Edge::North => {},
-            Edge::East => rotate_ccw(&mut south_tile),
-            Edge::South => flip_vert(&mut south_tile),
-            Edge::West => rotate_cw(&mut south_tile)
+            Edge::East => rotate_ccw(south_tile),
+            Edge::South => flip_vert(south_tile),
+            Edge::West => rotate_cw(south_tile)
         }
 
         // Flip vertically if necessary
         if south != south_tile.edge[Edge::North as usize] {
-            flip_horiz(&mut south_tile);
+            flip_horiz(south_tile);
         }
         assert!(south == south_tile.edge[Edge::North as usize], "South sides don't match");
     };
@@ -448,13 +493,13 @@ fn step_south(tile_num: TileNum, tiles: &mut TileMap, edges: &EdgeMap) -> Option
 
 fn find_side_norm(tile: &Tile, side: u16) -> Edge {
     if tile.edge_norm[Edge::North as usize] == side {
-        return Edge::North
+        Edge::North
     } else if tile.edge_norm[Edge::East as usize] == side {
-        return Edge::East
+        Edge::East
     } else if tile.edge_norm[Edge::South as usize] == side {
-        return Edge::South
+        Edge::South
     } else if tile.edge_norm[Edge::West as usize] == side {
-        return Edge::West
+        Edge::West
     } else {
         panic!("Edge not found")
     }
@@ -465,36 +510,24 @@ fn flip_horiz(tile: &mut Tile) {
         tile.tile_data[i] = reverse_bits(tile.tile_data[i]);
     }
 
-    let tmp1 = tile.edge_norm[Edge::West as usize];
-    tile.edge_norm[Edge::West as usize] = tile.edge_norm[Edge::East as usize];
-    tile.edge_norm[Edge::East as usize] = tmp1;
+    tile.edge_norm.swap(Edge::West as usize, Edge::East as usize);
 
-    let tmp2 = tile.edge[Edge::West as usize];
-    tile.edge[Edge::West as usize] = tile.edge[Edge::East as usize];
-    tile.edge[Edge::East as usize] = tmp2;
+    tile.edge.swap(Edge::West as usize, Edge::East as usize);
 
     tile.edge[Edge::North as usize] = reverse_bits(tile.edge[Edge::North as usize]);
     tile.edge[Edge::South as usize] = reverse_bits(tile.edge[Edge::South as usize]);
 }
 
 fn flip_vert(tile: &mut Tile) {
-    let mut save_data = [0; TILE_DIM];
-
-    for i in 0..TILE_DIM {
-        save_data[i] = tile.tile_data[i];
-    }
+    let save_data = tile.tile_data;
 
     for i in 0..TILE_DIM {
         tile.tile_data[i] = save_data[TILE_DIM - 1 - i];
     }
 
-    let tmp1 = tile.edge_norm[Edge::North as usize];
-    tile.edge_norm[Edge::North as usize] = tile.edge_norm[Edge::South as usize];
-    tile.edge_norm[Edge::South as usize] = tmp1;
+    tile.edge_norm.swap(Edge::North as usize, Edge::South as usize);
 
-    let tmp2 = tile.edge[Edge::North as usize];
-    tile.edge[Edge::North as usize] = tile.edge[Edge::South as usize];
-    tile.edge[Edge::South as usize] = tmp2;
+    tile.edge.swap(Edge::North as usize, Edge::South as usize);
 
     tile.edge[Edge::East as usize] = reverse_bits(tile.edge[Edge::East as usize]);
     tile.edge[Edge::West as usize] = reverse_bits(tile.edge[Edge::West as usize]);
@@ -530,11 +563,7 @@ fn test_flip_vert() {
 }
 
 fn rotate_cw(tile: &mut Tile) {
-    let mut save_data = [0; TILE_DIM];
-
-    for i in 0..TILE_DIM {
-        save_data[i] = tile.tile_data[i];
-    }
+    let save_data = tile.tile_data;
 
     // 2 1 0    8 5 2
     // 5 4 3 -> 7 4 1
@@ -590,11 +619,7 @@ fn test_rotate_cw() {
 }
 
 fn rotate_ccw(tile: &mut Tile) {
-    let mut save_data = [0; TILE_DIM];
-
-    for i in 0..TILE_DIM {
-        save_data[i] = tile.tile_data[i];
-    }
+    let save_data = tile.tile_data;
 
     // 2 1 0    0 3 6
     // 5 4 3 -> 1 4 7
@@ -606,8 +631,8 @@ fn rotate_ccw(tile: &mut Tile) {
         let mut dst_bit = 1 << (TILE_DIM - 1);
         tile.tile_data[i] = 0;
 
-        for j in 0..TILE_DIM {
-            if save_data[j] & src_bit != 0 {
+        for save in save_data.iter() {
+            if save & src_bit != 0 {
                 tile.tile_data[i] += dst_bit;
             }
 
@@ -649,7 +674,7 @@ fn test_rotate_ccw() {
     assert!(tile.tile_data[9] == 0b1000100000, "Elem 9 incorrect");
 }
 
-fn build_map(tiles: &TileMap, corner: TileNum) -> Vec<Vec<char>> {
+fn build_map(tiles: &TileMap, corner: TileNum) -> Grid<char> {
     let mut result: Vec<Vec<char>> = Vec::new();
 
     let mut cur_left_num = corner;
@@ -668,7 +693,7 @@ fn build_map(tiles: &TileMap, corner: TileNum) -> Vec<Vec<char>> {
             for i in 0..TILE_DIM - 2 {
                 let val = tile.tile_data[i + 1];
 
-                let mut bit = 1 << TILE_DIM - 2;
+                let mut bit = 1 << (TILE_DIM - 2);
 
                 for _ in 0..TILE_DIM - 2 {
                     result[y + i].push(if val & bit != 0 {
@@ -688,36 +713,57 @@ fn build_map(tiles: &TileMap, corner: TileNum) -> Vec<Vec<char>> {
         y += TILE_DIM - 2;
     }
 
-    result
+    Grid::from_rows(result).expect("Arranged tiles should form a square")
 }
 
-fn print_map(map: &Vec<Vec<char>>) {
-    for line in map {
-        println!("{}", line.iter().collect::<String>());
-    }
+fn print_map(map: &Grid<char>) {
+    debug_print!("{}", map.render(|&c| c));
 }
 
-// 01234567890123456789
-//                   # 
-// #    ##    ##    ###
-//  #  #  #  #  #  #   
-
-const MONSTER_OFFSETS: [[isize; 2]; 15]= [
-    [0, 0], [1, 1],
-    [3, 0], [1, -1], [1, 0], [1, 1],
-    [3, 0], [1, -1], [1, 0], [1, 1],
-    [3, 0], [1, -1], [1, 0], [0, -1], [1, 1]
+const MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   "
 ];
 
-fn find_monsters(map: &mut Vec<Vec<char>>) -> usize {
+/// Positions of the monster's body relative to its top left corner
+fn monster_offsets() -> Vec<(usize, usize)> {
+    MONSTER.iter().enumerate().flat_map(|(y, row)| {
+        row.char_indices().filter(|&(_, c)| c == '#').map(move |(x, _)| (x, y))
+    }).collect()
+}
+
+/// Finds the orientation of the map containing monsters, returning the number
+/// found and the map turned to that orientation with the monsters marked
+fn find_monsters(map: &Grid<char>) -> (usize, Grid<char>) {
+    let offsets = monster_offsets();
+
+    for mut orient in map.orientations() {
+        let found = mark_monsters(&mut orient, &offsets);
+
+        if found > 0 {
+            return (found, orient)
+        }
+    }
+
+    (0, map.clone())
+}
+
+fn mark_monsters(map: &mut Grid<char>, offsets: &[(usize, usize)]) -> usize {
     let mut found = 0;
 
-    for y in 0..map.len() {
-        for x in 0..map[y].len() {
-            if map[y][x] == '#' {
-                if find_monster(map, x as isize, y as isize) {
-                    found += 1;
+    for y in 0..map.height() {
+        for x in 0..map.width() {
+            let is_monster = offsets.iter().all(|&(ox, oy)| {
+                matches!(map.get((x + ox) as isize, (y + oy) as isize), Some('#') | Some('O'))
+            });
+
+            if is_monster {
+                for &(ox, oy) in offsets {
+                    map[(x + ox, y + oy)] = 'O';
                 }
+
+                found += 1;
             }
         }
     }
@@ -725,55 +771,77 @@ fn find_monsters(map: &mut Vec<Vec<char>>) -> usize {
     found
 }
 
-fn find_monster(map: &mut Vec<Vec<char>>, x: isize, y: isize) -> bool {
-    loop {
-        if find_monster_orient(map, x, y, 1, 1) { break };
-        if find_monster_orient(map, x, y, 1, -1) { break };
-        if find_monster_orient(map, x, y, -1, 1) { break };
-        if find_monster_orient(map, x, y, -1, -1) { break };
+fn count_hash(map: &Grid<char>) -> usize {
+    map.count(|&c| c == '#')
+}
 
-        return false
-    }
+#[test]
+fn test_example() {
+    let model = Day20::parse(&mut include_str!("../../example20.txt").as_bytes()).unwrap();
 
-    true
+    assert_eq!(20899048083289, Day20::part1(&model).unwrap());
+    assert_eq!(273, Day20::part2(&model).unwrap());
 }
 
-fn find_monster_orient(map: &mut Vec<Vec<char>>, x: isize, y: isize, xadd: isize, yadd: isize) -> bool {
-    let mut cx = x;
-    let mut cy = y;
+/// Random tile with its edges worked out
+#[cfg(test)]
+fn any_tile() -> impl Strategy<Value = Tile> {
+    prop::array::uniform10(0..1u16 << TILE_DIM).prop_map(|tile_data| {
+        let mut tile = Tile { tile_data, ..Default::default() };
+        calc_edges(&mut tile);
+        tile
+    })
+}
 
-    for o in MONSTER_OFFSETS.iter() {
-        cx += o[0] * xadd;
-        cy += o[1] * yadd;
+#[cfg(test)]
+fn tile_state(tile: &Tile) -> ([u16; TILE_DIM], [u16; 4], [u16; 4]) {
+    (tile.tile_data, tile.edge, tile.edge_norm)
+}
 
-        if cy < 0 || cy as usize >= map.len() {
-            return false
-        }
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn rotate_prop(tile in any_tile()) {
+        let mut rotated = tile.clone();
 
-        if cx < 0 || cx as usize >= map[cy as usize].len() {
-            return false
+        for _ in 0..4 {
+            rotate_cw(&mut rotated);
         }
 
-        if map[cy as usize][cx as usize] != '#' {
-            return false;
-        }
+        prop_assert_eq!(tile_state(&tile), tile_state(&rotated));
+
+        rotate_cw(&mut rotated);
+        rotate_ccw(&mut rotated);
+
+        prop_assert_eq!(tile_state(&tile), tile_state(&rotated));
     }
 
-    cx = x;
-    cy = y;
+    #[test]
+    fn flip_prop(tile in any_tile()) {
+        let mut flipped = tile.clone();
+
+        flip_horiz(&mut flipped);
+        flip_horiz(&mut flipped);
+
+        prop_assert_eq!(tile_state(&tile), tile_state(&flipped));
 
-    for o in MONSTER_OFFSETS.iter() {
-        cx += o[0] * xadd;
-        cy += o[1] * yadd;
+        flip_vert(&mut flipped);
+        flip_vert(&mut flipped);
 
-        map[cy as usize][cx as usize] = 'O'
+        prop_assert_eq!(tile_state(&tile), tile_state(&flipped));
     }
 
-    true
-}
+    #[test]
+    fn flip_edges_prop(tile in any_tile()) {
+        // Flipping keeps the edges in step with the data
+        let mut flipped = tile;
+
+        flip_horiz(&mut flipped);
+        flip_vert(&mut flipped);
 
-fn count_hash(map: &Vec<Vec<char>>) -> usize {
-    map.iter().map(|l| {
-        l.iter().filter(|&&c| c == '#').count()
-    }).sum()
+        let mut recalc = flipped.clone();
+        calc_edges(&mut recalc);
+
+        prop_assert_eq!(tile_state(&recalc), tile_state(&flipped));
+    }
 }
@@ -0,0 +1,146 @@
+use std::io::BufRead;
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_common::matching::{self, Assignment};
+use aoc_common::{debug, parse, ParseError, Solution};
+
+pub struct Day21;
+
+pub struct Food {
+    ingredients: Vec<String>,
+    allergens: Vec<String>
+}
+
+pub type Foods = Vec<Food>;
+
+/// Ingredients which could contain each allergen
+type Candidates<'a> = BTreeMap<&'a str, BTreeSet<&'a str>>;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Model = Foods;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_ingredients(input)
+    }
+
+    fn part1(foods: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let candidates = find_candidates(foods);
+
+        let suspect: BTreeSet<&str> = candidates.values().flatten().copied().collect();
+
+        // Count appearances of ingredients which can't contain any allergen
+        Ok(foods.iter().map(|food| {
+            food.ingredients.iter().filter(|i| !suspect.contains(&i[..])).count()
+        }).sum())
+    }
+
+    fn part2(foods: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let candidates = find_candidates(foods);
+
+        let translations = translate_allergens(&candidates)?;
+
+        // Candidates are sorted by allergen
+        Ok(translations.join(","))
+    }
+}
+
+fn load_ingredients(input: &mut dyn BufRead) -> Result<Foods, ParseError> {
+    let mut foods = Foods::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        let mut terms = line.text.split(" (contains ");
+
+        let ingredient_list = line.next(&mut terms, "ingredients")?;
+        let allergen_list = match line.next(&mut terms, "'(contains'")?.strip_suffix(")") {
+            Some(allergen_list) => allergen_list,
+            None => Err(line.missing("')'"))?
+        };
+
+        foods.push(Food {
+            ingredients: ingredient_list.split_whitespace().map(|s| s.to_string()).collect(),
+            allergens: allergen_list.split(",").map(|a| a.trim().to_string()).collect()
+        });
+    }
+
+    Ok(foods)
+}
+
+/// An allergen can only be in the ingredients common to every food listing it
+fn find_candidates(foods: &[Food]) -> Candidates<'_> {
+    let mut candidates = Candidates::new();
+
+    for food in foods {
+        let ingredients: BTreeSet<&str> = food.ingredients.iter().map(|i| &i[..]).collect();
+
+        for allergen in &food.allergens {
+            candidates.entry(allergen)
+                .and_modify(|c| c.retain(|i| ingredients.contains(i)))
+                .or_insert_with(|| ingredients.clone());
+        }
+    }
+
+    candidates
+}
+
+/// Matches each allergen with the one ingredient containing it
+fn translate_allergens<'a>(candidates: &Candidates<'a>) -> Result<Vec<&'a str>, String> {
+    let ingredients: Vec<&str> = candidates.values().flatten().copied().collect::<BTreeSet<_>>().into_iter().collect();
+
+    let index: Vec<Vec<usize>> = candidates.values().map(|c| {
+        c.iter().map(|i| ingredients.binary_search(i).unwrap()).collect()
+    }).collect();
+
+    match matching::assign(&index, ingredients.len()) {
+        Assignment::Unique(matching) => {
+            let translations: Vec<&str> = matching.iter().map(|&i| ingredients[i]).collect();
+
+            for (allergen, translation) in candidates.keys().zip(&translations) {
+                debug!("Translation for {} is {}", allergen, translation);
+            }
+
+            Ok(translations)
+        }
+        Assignment::Ambiguous { options, .. } => {
+            let alternatives: Vec<String> = candidates.keys().zip(options)
+                .filter(|(_, o)| o.len() > 1)
+                .map(|(allergen, o)| {
+                    let names: Vec<&str> = o.iter().map(|&i| ingredients[i]).collect();
+                    format!("{} could be {}", allergen, names.join(" or "))
+                }).collect();
+
+            Err(format!("Allergens are ambiguous: {}", alternatives.join(", ")))
+        }
+        Assignment::Impossible(matching) => {
+            let missing: Vec<&str> = candidates.keys().zip(matching)
+                .filter(|(_, m)| m.is_none())
+                .map(|(allergen, _)| *allergen)
+                .collect();
+
+            Err(format!("Couldn't translate allergens {}", missing.join(", ")))
+        }
+    }
+}
+
+#[test]
+fn test_example() {
+    let model = Day21::parse(&mut include_str!("../../example21.txt").as_bytes()).unwrap();
+
+    assert_eq!(5, Day21::part1(&model).unwrap());
+    assert_eq!("mxmxvkd,sqjhc,fvjkl", Day21::part2(&model).unwrap());
+}
+
+#[test]
+fn test_translate_errors() {
+    let model = Day21::parse(&mut "a b (contains x, y)\n".as_bytes()).unwrap();
+    assert_eq!("Allergens are ambiguous: x could be a or b, y could be a or b",
+        Day21::part2(&model).unwrap_err().to_string());
+
+    let model = Day21::parse(&mut "a b (contains x)\nc (contains x)\n".as_bytes()).unwrap();
+    assert_eq!("Couldn't translate allergens x", Day21::part2(&model).unwrap_err().to_string());
+}
@@ -0,0 +1,248 @@
+use std::io::BufRead;
+use std::collections::VecDeque;
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use aoc_common::{debug, parse, stat, Line, ParseError, Solution};
+
+pub struct Day22;
+
+pub type Hand = VecDeque<u16>;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Model = [Hand; 2];
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_hands(input)
+    }
+
+    fn part1(hands: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let mut hands = hands.clone();
+
+        debug!("Starting cards: {:?} ({}, {})", hands, hands[0].len(), hands[1].len());
+
+        play(&mut hands);
+
+        debug!("Ending cards: {:?} ({}, {})", hands, hands[0].len(), hands[1].len());
+        stat("winner", winner(&hands));
+
+        Ok(score_hands(&hands))
+    }
+
+    fn part2(hands: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let mut hands = hands.clone();
+
+        debug!("Starting cards: {:?} ({}, {})", hands, hands[0].len(), hands[1].len());
+
+        play_recursive(&mut hands);
+
+        debug!("Ending cards: {:?} ({}, {})", hands, hands[0].len(), hands[1].len());
+        stat("winner", winner(&hands));
+
+        Ok(score_hands(&hands))
+    }
+}
+
+fn load_hands(input: &mut dyn BufRead) -> Result<[Hand; 2], ParseError> {
+    let mut hands: [Hand; 2] = [Hand::new(), Hand::new()];
+    let mut headers: [Option<Line>; 2] = [None, None];
+    let mut cur_player: Option<usize> = None;
+
+    let mut lines = parse::lines(input);
+
+    for line_result in &mut lines {
+        let line = line_result?;
+
+        match cur_player {
+            None => {
+                if let Some(player_str) = line.text.strip_prefix("Player ") {
+                    let player_str = match player_str.strip_suffix(":") {
+                        Some(player_str) => player_str,
+                        None => Err(line.unexpected(player_str, "player number followed by ':'"))?
+                    };
+
+                    let player_no = line.parse::<usize>(player_str)?;
+
+                    if player_no < 1 || player_no > hands.len() {
+                        Err(line.invalid(player_str, "player must be 1 or 2"))?
+                    }
+
+                    if headers[player_no - 1].is_some() {
+                        Err(line.invalid(player_str, "duplicate player"))?
+                    }
+
+                    cur_player = Some(player_no - 1);
+                    headers[player_no - 1] = Some(line);
+                }
+            }
+            Some(player) => {
+                if line.text.is_empty() {
+                    cur_player = None
+                } else {
+                    hands[player].push_back(line.parse(&line.text)?)
+                }
+            }
+        }
+    }
+
+    // Both players must have cards
+    for (player, header) in headers.iter().enumerate() {
+        match header {
+            None => Err(lines.end(&format!("player {}", player + 1)))?,
+            Some(line) if hands[player].is_empty() => Err(line.missing("cards"))?,
+            _ => {}
+        }
+    }
+
+    Ok(hands)
+}
+
+fn play(hands: &mut [Hand]) {
+    while let Some(c1) = hands[0].pop_front() {
+        if let Some(c2) = hands[1].pop_front() {
+            if c1 > c2 {
+                // Player 1 win
+                hands[0].push_back(c1);
+                hands[0].push_back(c2);
+            } else {
+                // Player 2 win
+                hands[1].push_back(c2);
+                hands[1].push_back(c1);
+            }
+
+            debug!("Hands: {:?} ({}, {})", hands, hands[0].len(), hands[1].len());
+        } else {
+            hands[0].push_front(c1);
+            break
+        }
+    }
+}
+
+fn play_recursive(hands: &mut [Hand]) -> u8 {
+    let winner: u8;
+
+    let mut seen_hands: HashSet<u64> = HashSet::new();
+
+    loop {
+        // Seen these hands before?
+        let hash = hash_hands(hands);
+
+        match seen_hands.get(&hash) {
+            Some(_) => {
+                winner = 1;
+                break
+            },
+            None => {
+                seen_hands.insert(hash);
+            }
+        }
+
+        // Get player 1 card
+        if let Some(c1) = hands[0].pop_front() {
+            // Get player 2 card
+            if let Some(c2) = hands[1].pop_front() {
+                let hand_winner;
+
+                // Need to play a recursive game?
+                if c1 as usize <= hands[0].len() && c2 as usize <= hands[1].len() {
+                    // Yes
+                    hand_winner = recursive_play(hands, c1, c2)
+                } else {
+                    // No - greatest value card wins
+                    if c1 > c2 {
+                        hand_winner = 1;
+                    } else {
+                        hand_winner = 2;
+                    }
+                }
+
+                match hand_winner {
+                    1 => {
+                        // Player 1 win
+                        hands[0].push_back(c1);
+                        hands[0].push_back(c2);
+                    }
+                    2 => {
+                        // Player 2 win
+                        hands[1].push_back(c2);
+                        hands[1].push_back(c1);
+                    }
+                    _ => panic!("Invalid winner")
+                }
+
+            } else {
+                // Player 2 has no cards left
+                hands[0].push_front(c1);
+                winner = 1;
+                break
+
+            }
+
+        } else {
+            // Player 1 has no cards left
+            winner = 2;
+            break
+
+        }
+    }
+
+    winner
+}
+
+fn recursive_play(hands: &[Hand], c1: u16, c2: u16) -> u8 {
+    // Build hands for recursive play
+    let mut recurse_hands: [Hand; 2] = [
+        hands[0].iter().take(c1 as usize).copied().collect(),
+        hands[1].iter().take(c2 as usize).copied().collect()
+    ];
+
+    play_recursive(&mut recurse_hands)
+}
+
+fn hash_hands(hands: &[Hand]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hands.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The winning player's number, 1 or 2
+fn winner(hands: &[Hand]) -> usize {
+    if hands[0].is_empty() { 2 } else { 1 }
+}
+
+fn score_hands(hands: &[Hand]) -> u64 {
+    let mut score: u64 = 0;
+
+    for h in hands {
+        let hand_len = h.len();
+
+        for (i, &card) in h.iter().enumerate() {
+            score += card as u64 * (hand_len - i) as u64;
+        }
+    }
+
+    score
+}
+
+#[test]
+fn test_example() {
+    let model = Day22::parse(&mut include_str!("../../example22.txt").as_bytes()).unwrap();
+
+    assert_eq!(306, Day22::part1(&model).unwrap());
+    assert_eq!(291, Day22::part2(&model).unwrap());
+}
+
+#[test]
+fn load_hands_test() {
+    let err = |input: &str| Day22::parse(&mut input.as_bytes()).err().map(|e| e.to_string());
+
+    assert_eq!(Some("line 9 column 8: invalid '2': duplicate player".to_string()), err("Player 1:\n1\n2\n\nPlayer 2:\n3\n4\n\nPlayer 2:\n5"));
+    assert_eq!(Some("line 4 column 1: input ended, expecting player 2".to_string()), err("Player 1:\n1\n2\n"));
+    assert_eq!(Some("line 4 column 10: missing cards".to_string()), err("Player 1:\n1\n\nPlayer 2:\n"));
+    assert_eq!(None, err("Player 2:\n3\n\nPlayer 1:\n1\n"));
+}
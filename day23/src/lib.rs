@@ -0,0 +1,212 @@
+use std::io::BufRead;
+
+use aoc_common::{debug, debug_print, parse, stat, Line, ParseError, Solution};
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Model = Vec<usize>;
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_cups(input)
+    }
+
+    fn part1(cups: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let nexts = play(cups, cups.len(), 100, true);
+
+        // Labels after cup 1
+        Ok(seq_string(&nexts, 1, true))
+    }
+
+    fn part2(cups: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        let nexts = play(cups, 1_000_000, 10_000_000, false);
+
+        // Product of the two cups after cup 1
+        debug!("{} * {}", nexts[1], nexts[nexts[1]]);
+        stat("cup_a", nexts[1]);
+        stat("cup_b", nexts[nexts[1]]);
+
+        Ok(nexts[1] as u64 * nexts[nexts[1]] as u64)
+    }
+}
+
+fn load_cups(input: &mut dyn BufRead) -> Result<Vec<usize>, ParseError> {
+    let mut cups = None;
+
+    let mut lines = parse::lines(input);
+
+    for line in &mut lines {
+        let line = line?;
+
+        if line.text.is_empty() {
+            continue
+        }
+
+        if cups.is_some() {
+            Err(line.unexpected(&line.text, "a single line of cup labels"))?
+        }
+
+        cups = Some(parse_cups(&line)?);
+    }
+
+    match cups {
+        Some(cups) => Ok(cups),
+        None => Err(lines.end("cup labels"))
+    }
+}
+
+fn parse_cups(line: &Line) -> Result<Vec<usize>, ParseError> {
+    let labels = line.text.trim();
+
+    let mut cups: Vec<usize> = Vec::new();
+
+    for (i, c) in labels.char_indices() {
+        match c.to_digit(10) {
+            Some(label) => cups.push(label as usize),
+            None => Err(line.unexpected(&labels[i..i + c.len_utf8()], "cup label digit"))?
+        }
+    }
+
+    // Labels must be 1 to the number of cups, each used once
+    let mut seen = vec![false; cups.len() + 1];
+
+    for (i, &label) in cups.iter().enumerate() {
+        let token = &labels[i..i + 1];
+
+        if label == 0 || label > cups.len() {
+            Err(line.invalid(token, &format!("cup label out of range, expecting 1 to {}", cups.len())))?
+        }
+
+        if seen[label] {
+            Err(line.invalid(token, "cup label used more than once"))?
+        }
+
+        seen[label] = true;
+    }
+
+    if cups.len() < 5 {
+        Err(line.invalid(labels, &format!("at least 5 cups are needed, got {}", cups.len())))?
+    }
+
+    Ok(cups)
+}
+
+/// Plays the game with cups labelled 1 to size, returning the next cup for each label
+fn play(cups: &[usize], size: usize, moves: usize, trace: bool) -> Vec<usize> {
+    let mut nexts: Vec<usize> = Vec::new();
+
+    for _ in 0..=size + 1 {
+        nexts.push(0)
+    }
+
+    let mut start = cups[0];
+
+    for i in 1..cups.len() {
+        nexts[cups[i - 1]] = cups[i];
+    }
+
+    let mut prev = cups[cups.len() - 1];
+    for i in cups.len() + 1..=size {
+        nexts[prev] = i;
+        prev = i;
+    }
+
+    nexts[prev] = start;
+
+    if trace {
+        debug_print!("Start: ");
+        dump_seq(&nexts, start, false);
+    }
+
+    for m in 0..moves {
+        let int1 = nexts[start];
+        let int2 = nexts[int1];
+        let int3 = nexts[int2];
+
+        // Get next start number
+        let next_start = nexts[int3];
+
+        // Chop out the 3 numbers
+        nexts[start] = next_start;
+
+        // Work out destination
+        let mut dest = start - 1;
+        loop {
+            if dest == 0 {
+                dest = size;
+            }
+            if dest != int1 && dest != int2 && dest != int3 {
+                break
+            }
+            dest -= 1;
+        }
+
+        // Insert chopped numbers at destintion
+        nexts[int3] = nexts[dest];
+        nexts[dest] = int1;
+
+        // Move to next start
+        start = next_start;
+
+        if trace {
+            debug_print!("move {}: ", m + 1);
+            dump_seq(&nexts, start, false);
+        }
+    }
+
+    nexts
+}
+
+fn dump_seq(nexts: &[usize], start: usize, skip: bool) {
+    debug!("{}", seq_string(nexts, start, skip));
+}
+
+fn seq_string(nexts: &[usize], start: usize, skip: bool) -> String {
+    let mut result = String::new();
+    let mut next;
+    
+    if skip {
+        next = nexts[start];
+    } else {
+        next = start;
+    }
+
+    loop {
+        result.push_str(&next.to_string());
+        next = nexts[next];
+
+        if next == start {
+            break
+        }
+    }
+
+    result
+}
+
+#[test]
+fn test_parse_cups() {
+    assert_eq!(vec![3, 8, 9, 1, 2, 5, 4, 6, 7], parse_cups(&Line::new(1, "389125467")).unwrap());
+    assert!(parse_cups(&Line::new(1, "38912546a")).is_err(), "Non-digit label should fail");
+    assert!(parse_cups(&Line::new(1, "389125460")).is_err(), "Label 0 should fail");
+    assert!(parse_cups(&Line::new(1, "389125463")).is_err(), "Duplicate label should fail");
+    assert!(parse_cups(&Line::new(1, "3891")).is_err(), "Too few cups should fail");
+}
+
+#[test]
+fn test_example() {
+    let model = Day23::parse(&mut include_str!("../../example23.txt").as_bytes()).unwrap();
+
+    assert_eq!("67384529", Day23::part1(&model).unwrap());
+}
+
+#[test]
+#[ignore = "too slow for an unoptimised build"]
+fn test_example_part2() {
+    let model = Day23::parse(&mut include_str!("../../example23.txt").as_bytes()).unwrap();
+
+    assert_eq!(149245887792, Day23::part2(&model).unwrap());
+}
@@ -0,0 +1,152 @@
+use std::io::BufRead;
+use std::collections::HashSet;
+
+use aoc_common::ca::{Automaton, Hex, HexCoord, Rule};
+use aoc_common::{debug, parse, Line, ParseError, Solution};
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Model = Vec<HexCoord>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_tiles(input)
+    }
+
+    fn part1(tiles: &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        Ok(flip_initial(tiles).len())
+    }
+
+    fn part2(tiles: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        // Black tiles with 1 or 2 black neighbours stay black, white tiles with 2 flip
+        let mut floor = Automaton::new(Hex, Rule::new(&[2], &[1, 2]), flip_initial(tiles));
+
+        for i in 1..=100 {
+            floor.step();
+
+            debug!("Day {}, {} black tiles", i, floor.live().len());
+        }
+
+        Ok(floor.live().len())
+    }
+}
+
+type BlackSet = HashSet<HexCoord>;
+
+fn load_tiles(input: &mut dyn BufRead) -> Result<Vec<HexCoord>, ParseError> {
+    let mut tiles = Vec::new();
+
+    for line_result in parse::lines(input) {
+        let line = line_result?;
+
+        tiles.push(coord_from_string(&line)?);
+    }
+
+    Ok(tiles)
+}
+
+fn flip_initial(tiles: &[HexCoord]) -> BlackSet {
+    let mut black_set = HashSet::new();
+
+    for coord in tiles {
+        if black_set.contains(coord) {
+            black_set.remove(coord);
+        } else {
+            black_set.insert(coord.clone());
+        }
+    }
+
+    black_set
+}
+
+fn coord_from_string(line: &Line) -> Result<HexCoord, ParseError> {
+    let mut coord: HexCoord = Default::default();
+
+    let text = &line.text[..];
+    let mut line_chars = text.char_indices();
+
+    // Builds the error for the direction starting at pos
+    let bad_direction = |pos: usize, len: usize| {
+        line.unexpected(&text[pos..pos + len], "'e', 'se', 'sw', 'w', 'nw' or 'ne'")
+    };
+
+    while let Some((pos, c1)) = line_chars.next() {
+        match c1 {
+            'e' => coord.q += 1,
+            'w' => coord.q -= 1,
+            'n' | 's' => {
+                match line_chars.next() {
+                    Some((_, c2)) => {
+                        match c2 {
+                            'e' => if coord.r & 1 == 1 { coord.q += 1 },
+                            'w' => if coord.r & 1 == 0 { coord.q -= 1 },
+                            _ => Err(bad_direction(pos, 1 + c2.len_utf8()))?
+                        }
+                    }
+                    None => Err(bad_direction(pos, 1))?
+                }
+
+                if c1 == 'n' {
+                    coord.r -= 1;
+                } else {
+                    coord.r += 1;
+                }
+            }
+            _ => Err(bad_direction(pos, c1.len_utf8()))?
+        }
+    }
+
+    Ok(coord)
+}
+
+#[test]
+fn test_neig() {
+    use aoc_common::ca::Topology;
+
+    let neighbours = |q, r| Hex.neighbours(&HexCoord { q, r }).into_iter().collect::<BlackSet>();
+
+    // Around r3 q3
+    assert_eq!(vec![(3, 2), (4, 2), (2, 3), (4, 3), (3, 4), (4, 4)].into_iter().map(|(q, r)| HexCoord { q, r }).collect::<BlackSet>(),
+        neighbours(3, 3));
+
+    // Around r4 q4
+    assert_eq!(vec![(3, 3), (4, 3), (3, 4), (5, 4), (3, 5), (4, 5)].into_iter().map(|(q, r)| HexCoord { q, r }).collect::<BlackSet>(),
+        neighbours(4, 4));
+}
+
+#[test]
+fn test_example() {
+    let model = Day24::parse(&mut include_str!("../../example24.txt").as_bytes()).unwrap();
+
+    assert_eq!(10, Day24::part1(&model).unwrap());
+    assert_eq!(2208, Day24::part2(&model).unwrap());
+}
+
+/// Random directions where each one is followed at some point by its opposite
+#[cfg(test)]
+fn cancelling_path() -> impl Strategy<Value = Vec<&'static str>> {
+    let dirs = ["e", "se", "sw", "w", "nw", "ne"];
+
+    prop::collection::vec(0..6usize, 0..40).prop_flat_map(move |path| {
+        let steps: Vec<&str> = path.iter().flat_map(|&d| vec![dirs[d], dirs[(d + 3) % 6]]).collect();
+
+        Just(steps).prop_shuffle()
+    })
+}
+
+#[cfg(test)]
+proptest! {
+    #[test]
+    fn cancelling_path_prop(path in cancelling_path()) {
+        let tiles = Day24::parse(&mut format!("{}\n", path.concat()).as_bytes()).unwrap();
+
+        prop_assert_eq!(vec![HexCoord::default()], tiles);
+    }
+}
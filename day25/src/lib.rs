@@ -0,0 +1,133 @@
+use std::io::BufRead;
+
+use aoc_common::numtheory::{discrete_log, modpow, NumError};
+use aoc_common::{debug, parse, stat, Line, ParseError, Solution};
+
+pub struct Day25;
+
+type NumType = i64;
+
+const MODULUS: NumType = 20201227;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const PARTS: u8 = 1;
+
+    type Model = (NumType, NumType);
+    type Answer1 = NumType;
+    type Answer2 = NumType;
+
+    fn parse(input: &mut dyn BufRead) -> Result<Self::Model, ParseError> {
+        load_keys(input)
+    }
+
+    fn part1(&(pk1, pk2): &Self::Model) -> Result<Self::Answer1, Box<dyn std::error::Error>> {
+        let subject: NumType = 7;
+
+        let loop1 = calc_loop(pk1, subject)?;
+        debug!("Loop 1 is {}", loop1);
+
+        let loop2 = calc_loop(pk2, subject)?;
+        debug!("Loop 2 is {}", loop2);
+
+        stat("loop1", loop1);
+        stat("loop2", loop2);
+
+        let ek1: NumType = calc_ek(pk1, loop2)?;
+        debug!("Encryption key is {}", ek1);
+
+        let ek2: NumType = calc_ek(pk2, loop1)?;
+        debug!("Crosscheck encryption key is {}", ek2);
+
+        if ek1 != ek2 {
+            Err(format!("Encryption keys {} and {} differ", ek1, ek2))?
+        }
+
+        Ok(ek1)
+    }
+
+    fn part2(_keys: &Self::Model) -> Result<Self::Answer2, Box<dyn std::error::Error>> {
+        Err("Day 25 has no part 2")?
+    }
+}
+
+fn load_keys(input: &mut dyn BufRead) -> Result<(NumType, NumType), ParseError> {
+    let mut keys: Vec<NumType> = Vec::new();
+
+    let mut lines = parse::lines(input);
+
+    for line in &mut lines {
+        let line = line?;
+
+        if line.text.is_empty() {
+            continue
+        }
+
+        if keys.len() == 2 {
+            Err(line.unexpected(&line.text, "2 public keys only"))?
+        }
+
+        keys.push(parse_key(&line)?);
+    }
+
+    match keys[..] {
+        [pk1, pk2] => Ok((pk1, pk2)),
+        _ => Err(lines.end("public key"))
+    }
+}
+
+fn parse_key(line: &Line) -> Result<NumType, ParseError> {
+    let token = line.text.trim();
+
+    let key = line.parse::<NumType>(token)?;
+
+    // Keys are powers of the subject number so must be 1 to MODULUS - 1
+    if key <= 0 || key >= MODULUS {
+        Err(line.invalid(token, &format!("public key must be between 1 and {}", MODULUS - 1)))?
+    }
+
+    Ok(key)
+}
+
+/// The number of transforms of subject giving the public key
+fn calc_loop(pk: NumType, subject: NumType) -> Result<NumType, NumError> {
+    discrete_log(subject, pk, MODULUS)
+}
+
+/// Transforms the public key loop_cnt times
+fn calc_ek(pk: NumType, loop_cnt: NumType) -> Result<NumType, NumError> {
+    modpow(pk, loop_cnt, MODULUS)
+}
+
+#[test]
+fn test_calc_loop() {
+    let pk1: NumType = 5764801;
+    let pk2: NumType = 17807724;
+
+    let loop1 = calc_loop(pk1, 7).unwrap();
+    assert!(loop1 == 8, "Loop 1 size incorrect");
+
+    let loop2 = calc_loop(pk2, 7).unwrap();
+    assert!(loop2 == 11, "Loop 2 size incorrect");
+
+    let ek1 = calc_ek(pk1, loop2).unwrap();
+    assert!(ek1 == 14897079, "Encryption key 1 incorrect");
+
+    let ek2 = calc_ek(pk2, loop1).unwrap();
+    assert!(ek2 == 14897079, "Encryption key 2 incorrect");
+}
+
+#[test]
+fn test_parse_key() {
+    assert_eq!(5764801, parse_key(&Line::new(1, "5764801")).unwrap());
+    assert!(parse_key(&Line::new(1, "abc")).is_err(), "Non-numeric key should fail");
+    assert!(parse_key(&Line::new(1, "0")).is_err(), "Zero key should fail");
+    assert!(parse_key(&Line::new(1, "20201227")).is_err(), "Key equal to MODULUS should fail");
+}
+
+#[test]
+fn test_example() {
+    let model = Day25::parse(&mut include_str!("../../example25.txt").as_bytes()).unwrap();
+
+    assert_eq!(14897079, Day25::part1(&model).unwrap());
+}
@@ -0,0 +1,7 @@
+use aoc_common::Solution;
+
+/// Feeds arbitrary bytes to a day's parser. Any input must give a model or a
+/// parse error, so panics and hangs are reported by the fuzzer
+pub fn parse<S: Solution>(data: &[u8]) {
+    let _ = S::parse(&mut &data[..]);
+}